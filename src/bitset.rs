@@ -0,0 +1,184 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+const WORD_BITS: usize = usize::BITS as usize;
+
+/// Fixed-capacity bit set backed by `N` machine words
+///
+/// The set holds `N * usize::BITS` bits. Bit indices outside that range
+/// panic, like out-of-bounds array indexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSet<const N: usize> {
+    words: [usize; N],
+}
+
+impl<const N: usize> BitSet<N> {
+    /// Number of bits the set can hold
+    pub const BITS: usize = N * WORD_BITS;
+
+    /// Create a new set with every bit cleared
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Word count N must be greater than zero") };
+        Self { words: [0; N] }
+    }
+
+    /// Memory footprint of this set type in bytes, exactly `N` words
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Set bit `i`
+    pub fn set(&mut self, i: usize) {
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+    }
+
+    /// Clear bit `i`
+    pub fn clear(&mut self, i: usize) {
+        self.words[i / WORD_BITS] &= !(1 << (i % WORD_BITS));
+    }
+
+    /// Flip bit `i`
+    pub fn toggle(&mut self, i: usize) {
+        self.words[i / WORD_BITS] ^= 1 << (i % WORD_BITS);
+    }
+
+    /// Check if bit `i` is set
+    pub fn get(&self, i: usize) -> bool {
+        self.words[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0
+    }
+
+    /// Count the set bits
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Check if no bit is set
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Return the number of bits the set can hold
+    pub fn capacity(&self) -> usize {
+        Self::BITS
+    }
+
+    /// Clear every bit
+    pub fn clear_all(&mut self) {
+        self.words = [0; N];
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+        impl<const N: usize> $assign for BitSet<N> {
+            fn $assign_method(&mut self, rhs: Self) {
+                for (w, r) in self.words.iter_mut().zip(rhs.words) {
+                    w.$assign_method(r);
+                }
+            }
+        }
+
+        impl<const N: usize> $op for BitSet<N> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_operations() {
+        let mut set: BitSet<2> = BitSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.capacity(), 2 * WORD_BITS);
+
+        // Bits on both sides of the word boundary
+        let edge = WORD_BITS;
+        set.set(0);
+        set.set(edge - 1);
+        set.set(edge);
+        set.set(2 * edge - 1);
+        assert!(set.get(edge - 1) && set.get(edge));
+        assert!(!set.get(1));
+        assert_eq!(set.count_ones(), 4);
+
+        set.clear(edge);
+        assert!(!set.get(edge));
+        set.toggle(edge);
+        set.toggle(0);
+        assert!(set.get(edge));
+        assert!(!set.get(0));
+        assert_eq!(set.count_ones(), 3);
+
+        set.clear_all();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let mut set: BitSet<1> = BitSet::new();
+        set.set(WORD_BITS);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let edge = WORD_BITS;
+        let mut a: BitSet<2> = BitSet::new();
+        let mut b: BitSet<2> = BitSet::new();
+        for i in [1, edge - 1, edge + 3] {
+            a.set(i);
+        }
+        for i in [edge - 1, edge + 3, edge + 5] {
+            b.set(i);
+        }
+
+        let and = a & b;
+        assert_eq!(and.count_ones(), 2);
+        assert!(and.get(edge - 1) && and.get(edge + 3));
+
+        let or = a | b;
+        assert_eq!(or.count_ones(), 4);
+        assert!(or.get(1) && or.get(edge + 5));
+
+        let xor = a ^ b;
+        assert_eq!(xor.count_ones(), 2);
+        assert!(xor.get(1) && xor.get(edge + 5));
+
+        a ^= a;
+        assert!(a.is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use core::mem::MaybeUninit;
+
+/// Ping-pong double buffer for frame handoff
+///
+/// A producer fills the back buffer while a consumer reads the front one;
+/// `swap` exchanges the roles by flipping an index, without copying.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleBuffer<T: Copy, const N: usize> {
+    buffers: [[T; N]; 2],
+    front: usize, // Index of the readable buffer
+}
+
+impl<T: Copy, const N: usize> DoubleBuffer<T, N> {
+    /// Create a new double buffer with both halves filled by `default_value`
+    pub const fn new(default_value: T) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            buffers: [[default_value; N]; 2],
+            front: 0,
+        }
+    }
+
+    /// Memory footprint of this double buffer type in bytes
+    ///
+    /// Roughly `2 * N * size_of::<T>()` plus one `usize` for the front index.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Get the readable front buffer
+    pub fn front(&self) -> &[T; N] {
+        &self.buffers[self.front]
+    }
+
+    /// Get the writable back buffer
+    pub fn back_mut(&mut self) -> &mut [T; N] {
+        &mut self.buffers[1 - self.front]
+    }
+
+    /// Publish the back buffer as the new front buffer
+    ///
+    /// The previous front buffer becomes the back buffer with its old
+    /// contents intact.
+    pub fn swap(&mut self) {
+        self.front = 1 - self.front;
+    }
+}
+
+/// Circular log of the most recent `N` timestamped records
+///
+/// Once full, each new record overwrites the oldest one.
+pub struct LogBuffer<T: Copy, const N: usize> {
+    records: [MaybeUninit<(u64, T)>; N],
+    next: usize, // Slot the next record is written to
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> LogBuffer<T, N> {
+    /// Create a new empty log
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            records: [const { MaybeUninit::uninit() }; N],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this log type in bytes
+    ///
+    /// Roughly `N` timestamped records plus two `usize` for the write
+    /// position and the length.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Append a record, overwriting the oldest one when full
+    pub fn record(&mut self, timestamp: u64, value: T) {
+        self.records[self.next].write((timestamp, value));
+        self.next = (self.next + 1) % N;
+        if self.len < N {
+            self.len += 1;
+        }
+    }
+
+    /// Get the most recent record
+    pub fn latest(&self) -> Option<(u64, T)> {
+        self.iter_recent().next()
+    }
+
+    /// Iterate over the records from newest to oldest
+    pub fn iter_recent(&self) -> impl Iterator<Item = (u64, T)> + '_ {
+        // Safety: The `len` slots behind `next` are initialized
+        (1..=self.len).map(move |i| unsafe { self.records[(self.next + N - i) % N].assume_init() })
+    }
+
+    /// Check if the log is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the log has started overwriting old records
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the number of stored records
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Discard every record
+    pub fn clear(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy, const N: usize> Default for LogBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_buffer() {
+        let mut buf: DoubleBuffer<u8, 4> = DoubleBuffer::new(0);
+        buf.back_mut().copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(buf.front(), &[0; 4]); // Not published yet
+
+        buf.swap();
+        assert_eq!(buf.front(), &[1, 2, 3, 4]);
+
+        // Writing the new back buffer leaves the front untouched
+        buf.back_mut()[0] = 9;
+        assert_eq!(buf.front(), &[1, 2, 3, 4]);
+
+        buf.swap();
+        assert_eq!(buf.front(), &[9, 0, 0, 0]);
+        assert_eq!(buf.back_mut(), &mut [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_log_buffer() {
+        let mut log: LogBuffer<i16, 3> = LogBuffer::new();
+        assert!(log.is_empty());
+        assert_eq!(log.latest(), None);
+
+        log.record(100, 1);
+        log.record(200, 2);
+        assert!(log.iter_recent().eq([(200, 2), (100, 1)]));
+
+        // Five events into three slots keep only the newest three
+        log.record(300, 3);
+        log.record(400, 4);
+        log.record(500, 5);
+        assert!(log.is_full());
+        assert_eq!(log.len(), 3);
+        assert!(log.iter_recent().eq([(500, 5), (400, 4), (300, 3)]));
+        assert_eq!(log.latest(), Some((500, 5)));
+
+        log.clear();
+        assert_eq!(log.iter_recent().count(), 0);
+    }
+}
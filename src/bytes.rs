@@ -0,0 +1,74 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+/// Fixed-size plain value with a native-endian byte representation
+///
+/// Implemented for the primitive integer and floating-point types via
+/// their `to_ne_bytes`/`from_ne_bytes`, so no unsafe transmutes are needed.
+pub trait NeBytes: Copy {
+    /// Number of bytes in the encoding
+    const SIZE: usize;
+
+    /// All-zero value
+    const ZERO: Self;
+
+    /// Write the encoding into `out[..Self::SIZE]`
+    fn write_ne(self, out: &mut [u8]);
+
+    /// Read a value from `bytes[..Self::SIZE]`
+    fn read_ne(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_ne_bytes {
+    ($zero:expr => $($t:ty),*) => {
+        $(
+            impl NeBytes for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+                const ZERO: Self = $zero;
+
+                fn write_ne(self, out: &mut [u8]) {
+                    out[..Self::SIZE].copy_from_slice(&self.to_ne_bytes());
+                }
+
+                fn read_ne(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; core::mem::size_of::<$t>()];
+                    buf.copy_from_slice(&bytes[..Self::SIZE]);
+                    <$t>::from_ne_bytes(buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_ne_bytes!(0 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_ne_bytes!(0.0 => f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut buf = [0u8; 16];
+        (-2i16).write_ne(&mut buf);
+        assert_eq!(i16::read_ne(&buf), -2);
+
+        1.5f64.write_ne(&mut buf[3..]);
+        assert_eq!(f64::read_ne(&buf[3..]), 1.5);
+        assert_eq!(u128::SIZE, 16);
+    }
+}
@@ -0,0 +1,266 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+/// Marker for the end of the recency list
+const NIL: usize = usize::MAX;
+
+/// Fixed-size least-recently-used cache
+///
+/// Entries live in parallel arrays and are linked into a recency list by
+/// index, most recently used first. Lookups are a linear scan over the
+/// keys, so the cache is meant for small `N`.
+pub struct LruCache<K: PartialEq + Copy, V, const N: usize> {
+    keys: [MaybeUninit<K>; N],
+    values: [MaybeUninit<V>; N],
+    prev: [usize; N],
+    next: [usize; N],
+    head: usize, // Most recently used slot
+    tail: usize, // Least recently used slot
+    len: usize,  // Slots `0..len` are occupied
+}
+
+impl<K: PartialEq + Copy, V, const N: usize> LruCache<K, V, N> {
+    /// Create a new empty cache
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            keys: [const { MaybeUninit::uninit() }; N],
+            values: [const { MaybeUninit::uninit() }; N],
+            prev: [NIL; N],
+            next: [NIL; N],
+            head: NIL,
+            tail: NIL,
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this cache type in bytes
+    ///
+    /// Each of the `N` entries costs `size_of::<K>() + size_of::<V>()` plus two
+    /// `usize` recency links.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Find the slot holding `key`
+    fn find(&self, key: K) -> Option<usize> {
+        // Safety: Slots below `len` are initialized
+        (0..self.len).find(|&i| unsafe { self.keys[i].assume_init() } == key)
+    }
+
+    /// Unlink a slot from the recency list
+    fn detach(&mut self, i: usize) {
+        let (prev, next) = (self.prev[i], self.next[i]);
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.next[prev] = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.prev[next] = prev;
+        }
+    }
+
+    /// Link a slot in as the most recently used
+    fn attach_front(&mut self, i: usize) {
+        self.prev[i] = NIL;
+        self.next[i] = self.head;
+        if self.head == NIL {
+            self.tail = i;
+        } else {
+            self.prev[self.head] = i;
+        }
+        self.head = i;
+    }
+
+    /// Look up `key` and mark it as the most recently used entry
+    pub fn get(&mut self, key: K) -> Option<&V> {
+        let i = self.find(key)?;
+        self.detach(i);
+        self.attach_front(i);
+        // Safety: The slot was found among the occupied ones
+        Some(unsafe { self.values[i].assume_init_ref() })
+    }
+
+    /// Look up `key` without changing the recency order
+    pub fn peek(&self, key: K) -> Option<&V> {
+        let i = self.find(key)?;
+        // Safety: The slot was found among the occupied ones
+        Some(unsafe { self.values[i].assume_init_ref() })
+    }
+
+    /// Check if `key` is cached, without changing the recency order
+    pub fn contains(&self, key: K) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Insert or update an entry and mark it as the most recently used
+    ///
+    /// # Return value
+    /// - `Some(V)`: The previous value for `key`, or the least recently used
+    ///   value that was evicted to make room
+    /// - `None`: The entry was stored in a free slot
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        let (i, old) = if let Some(i) = self.find(key) {
+            self.detach(i);
+            // Safety: The slot was found among the occupied ones
+            let old = mem::replace(unsafe { self.values[i].assume_init_mut() }, value);
+            (i, Some(old))
+        } else if self.len < N {
+            let i = self.len;
+            self.keys[i].write(key);
+            self.values[i].write(value);
+            self.len += 1;
+            (i, None)
+        } else {
+            let i = self.tail;
+            self.detach(i);
+            self.keys[i].write(key);
+            // Safety: The tail slot is occupied when the cache is full
+            let old = mem::replace(unsafe { self.values[i].assume_init_mut() }, value);
+            (i, Some(old))
+        };
+
+        self.attach_front(i);
+        old
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the cache is full
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the current number of entries in the cache
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of entries the cache can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove and drop all entries
+    pub fn clear(&mut self) {
+        let len = self.len;
+        // Reset first so a panicking destructor cannot cause a double drop
+        self.len = 0;
+        self.head = NIL;
+        self.tail = NIL;
+        for slot in &mut self.values[..len] {
+            unsafe {
+                ptr::drop_in_place(slot.as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<K: PartialEq + Copy, V, const N: usize> Default for LruCache<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drop handler to process initialized values
+impl<K: PartialEq + Copy, V, const N: usize> Drop for LruCache<K, V, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: PartialEq + Copy, V, const N: usize> FixedCapacity for LruCache<K, V, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::DropCounter;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_eviction_order() {
+        let mut cache: LruCache<u32, &str, 3> = LruCache::new();
+        assert_eq!(cache.put(1, "one"), None);
+        assert_eq!(cache.put(2, "two"), None);
+        assert_eq!(cache.put(3, "three"), None);
+        assert!(cache.is_full());
+
+        // Oldest entries go first
+        assert_eq!(cache.put(4, "four"), Some("one"));
+        assert_eq!(cache.put(5, "five"), Some("two"));
+        assert!(!cache.contains(1));
+        assert!(!cache.contains(2));
+        assert_eq!(cache.len(), 3);
+
+        // Updating an existing key returns the old value without evicting
+        assert_eq!(cache.put(4, "FOUR"), Some("four"));
+        assert_eq!(cache.put(6, "six"), Some("three"));
+        assert_eq!(cache.peek(4), Some(&"FOUR"));
+    }
+
+    #[test]
+    fn test_get_updates_recency() {
+        let mut cache: LruCache<u32, i32, 3> = LruCache::new();
+        cache.put(1, 10);
+        cache.put(2, 20);
+        cache.put(3, 30);
+
+        // Touch the oldest entry so 2 becomes the eviction candidate
+        assert_eq!(cache.get(1), Some(&10));
+        assert_eq!(cache.put(4, 40), Some(20));
+
+        // `peek` must not promote 3
+        assert_eq!(cache.peek(3), Some(&30));
+        assert_eq!(cache.put(5, 50), Some(30));
+
+        assert_eq!(cache.get(2), None);
+        assert_eq!(cache.get(1), Some(&10));
+        assert_eq!(cache.get(4), Some(&40));
+        assert_eq!(cache.get(5), Some(&50));
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+        {
+            let mut cache: LruCache<u8, DropCounter, 2> = LruCache::new();
+            assert!(cache.put(1, DropCounter(&drops)).is_none());
+            assert!(cache.put(2, DropCounter(&drops)).is_none());
+            drop(cache.put(3, DropCounter(&drops)));
+            assert_eq!(drops.get(), 1);
+        }
+        assert_eq!(drops.get(), 3);
+    }
+}
@@ -0,0 +1,165 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+/// Common size queries of the fixed-capacity containers
+///
+/// Lets generic code inspect how full any of the crate's containers is.
+/// Every container rejects a zero capacity at compile time, so `capacity`
+/// never returns zero.
+pub trait FixedCapacity {
+    /// Return the maximum number of elements the container can hold
+    fn capacity(&self) -> usize;
+
+    /// Return the current number of elements
+    fn len(&self) -> usize;
+
+    /// Check if the container is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if the container is full
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Return the number of elements that can still be added
+    fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::{DoubleBuffer, LogBuffer};
+    use crate::checksum::RollingChecksum;
+    use crate::heap::PriorityQueue;
+    use crate::queue::{AdvancedArrayQueue, ArrayQueue};
+    use crate::stack::{AdvancedArrayStack, ArrayStack, MaxStack, MinStack};
+    use crate::string::SmallString;
+    use crate::tree::ArrayBinaryTree;
+    use crate::vec::ArrayVec;
+
+    /// Fill level in percent
+    fn usage<C: FixedCapacity>(container: &C) -> usize {
+        container.len() * 100 / container.capacity()
+    }
+
+    #[test]
+    fn test_generic_usage() {
+        let mut queue = ArrayQueue::<i32, 4>::new(0);
+        queue.enqueue(1).unwrap();
+        assert_eq!(usage(&queue), 25);
+
+        let mut advanced_queue: AdvancedArrayQueue<i32, 2> = AdvancedArrayQueue::new();
+        advanced_queue.enqueue(1).unwrap();
+        assert_eq!(usage(&advanced_queue), 50);
+
+        let mut stack: ArrayStack<i32, 5> = ArrayStack::new(0);
+        stack.push_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(usage(&stack), 100);
+        assert!(FixedCapacity::is_full(&stack));
+
+        let advanced_stack: AdvancedArrayStack<i32, 3> = AdvancedArrayStack::new();
+        assert_eq!(usage(&advanced_stack), 0);
+        assert!(FixedCapacity::is_empty(&advanced_stack));
+
+        let mut tree: ArrayBinaryTree<i32, 4> = ArrayBinaryTree::new();
+        tree.insert_root(1).unwrap();
+        tree.insert_left(0, 2).unwrap();
+        tree.insert_right(0, 3).unwrap();
+        assert_eq!(usage(&tree), 75);
+        assert_eq!(FixedCapacity::remaining(&tree), 1);
+
+        let mut vec: ArrayVec<u8, 8> = ArrayVec::new();
+        vec.push(1).unwrap();
+        assert_eq!(usage(&vec), 12);
+
+        let mut string: SmallString<10> = SmallString::new();
+        string.push_str("hello").unwrap();
+        assert_eq!(usage(&string), 50);
+
+        let mut checksum = RollingChecksum::<4>::new();
+        checksum.push(b'a');
+        assert_eq!(usage(&checksum), 25);
+        for &b in b"bcde" {
+            checksum.push(b);
+        }
+        assert!(FixedCapacity::is_full(&checksum));
+    }
+
+    #[test]
+    fn test_size_bytes() {
+        use core::mem::size_of;
+
+        assert_eq!(
+            ArrayQueue::<u8, 16>::size_bytes(),
+            size_of::<ArrayQueue<u8, 16>>()
+        );
+        assert_eq!(
+            AdvancedArrayQueue::<u32, 8>::size_bytes(),
+            size_of::<AdvancedArrayQueue<u32, 8>>()
+        );
+        assert_eq!(
+            ArrayStack::<u64, 4>::size_bytes(),
+            size_of::<ArrayStack<u64, 4>>()
+        );
+        assert_eq!(
+            ArrayBinaryTree::<i16, 7>::size_bytes(),
+            size_of::<ArrayBinaryTree<i16, 7>>()
+        );
+
+        // Usable in constant expressions, e.g. to budget static memory
+        const VEC_BYTES: usize = ArrayVec::<u32, 10>::size_bytes();
+        assert_eq!(VEC_BYTES, 10 * 4 + size_of::<usize>());
+        assert_eq!(
+            AdvancedArrayStack::<u64, 3>::size_bytes(),
+            3 * 8 + size_of::<usize>()
+        );
+        assert_eq!(
+            crate::bitset::BitSet::<3>::size_bytes(),
+            3 * size_of::<usize>()
+        );
+
+        assert_eq!(
+            MinStack::<u64, 4>::size_bytes(),
+            2 * (4 * 8 + size_of::<usize>())
+        );
+        assert_eq!(
+            MaxStack::<u64, 4>::size_bytes(),
+            size_of::<MaxStack<u64, 4>>()
+        );
+        assert_eq!(
+            PriorityQueue::<u32, u8, 4>::size_bytes(),
+            size_of::<PriorityQueue<u32, u8, 4>>()
+        );
+        assert_eq!(
+            DoubleBuffer::<u32, 8>::size_bytes(),
+            2 * 8 * 4 + size_of::<usize>()
+        );
+        assert_eq!(
+            LogBuffer::<u64, 4>::size_bytes(),
+            4 * 16 + 2 * size_of::<usize>()
+        );
+        assert_eq!(SmallString::<24>::size_bytes(), 24 + size_of::<usize>());
+        assert_eq!(
+            RollingChecksum::<16>::size_bytes(),
+            size_of::<RollingChecksum<16>>()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use crate::queue::ArrayQueue;
+
+/// Polynomial base of the rolling hash
+const BASE: u32 = 257;
+
+/// Rabin-Karp style rolling hash over the last `N` bytes of a stream
+///
+/// The hash of a window `w[0..k]` is `sum(w[i] * BASE^(k - 1 - i))` with
+/// wrapping `u32` arithmetic. Sliding by one byte costs O(1), so repeated
+/// patterns can be spotted without rehashing the whole window.
+pub struct RollingChecksum<const N: usize> {
+    window: ArrayQueue<u8, N>,
+    hash: u32,
+    top_power: u32, // BASE^(N - 1), weight of the oldest byte in a full window
+}
+
+impl<const N: usize> RollingChecksum<N> {
+    /// Create a new checksum over an empty window
+    pub fn new() -> Self {
+        let mut top_power: u32 = 1;
+        for _ in 1..N {
+            top_power = top_power.wrapping_mul(BASE);
+        }
+
+        Self {
+            window: ArrayQueue::new(0),
+            hash: 0,
+            top_power,
+        }
+    }
+
+    /// Memory footprint of this checksum type in bytes
+    ///
+    /// Roughly `N` bytes of window plus the queue indices and two `u32` for
+    /// the hash state.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Compute the hash of `bytes` from scratch
+    pub fn hash_of(bytes: &[u8]) -> u32 {
+        bytes.iter().fold(0u32, |hash, &b| {
+            hash.wrapping_mul(BASE).wrapping_add(b as u32)
+        })
+    }
+
+    /// Slide the window by one byte and return the new hash
+    pub fn push(&mut self, byte: u8) -> u32 {
+        if self.window.is_full()
+            && let Some(oldest) = self.window.dequeue()
+        {
+            self.hash = self
+                .hash
+                .wrapping_sub((oldest as u32).wrapping_mul(self.top_power));
+        }
+        // Cannot fail, a slot was freed above if needed
+        let _ = self.window.enqueue(byte);
+
+        self.hash = self.hash.wrapping_mul(BASE).wrapping_add(byte as u32);
+        self.hash
+    }
+
+    /// Get the hash of the current window
+    pub fn value(&self) -> u32 {
+        self.hash
+    }
+
+    /// Check if the window holds `N` bytes
+    pub fn is_full(&self) -> bool {
+        self.window.is_full()
+    }
+
+    /// Empty the window
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.hash = 0;
+    }
+}
+
+impl<const N: usize> FixedCapacity for RollingChecksum<N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+}
+
+impl<const N: usize> Default for RollingChecksum<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_matches_scratch() {
+        let stream = b"the quick brown fox jumps over the lazy dog";
+        let mut rolling = RollingChecksum::<5>::new();
+
+        for (i, &b) in stream.iter().enumerate() {
+            let hash = rolling.push(b);
+            let start = (i + 1).saturating_sub(5);
+            assert_eq!(hash, RollingChecksum::<5>::hash_of(&stream[start..=i]));
+        }
+        assert!(rolling.is_full());
+
+        rolling.reset();
+        assert_eq!(rolling.value(), 0);
+        rolling.push(b'a');
+        assert_eq!(rolling.value(), RollingChecksum::<5>::hash_of(b"a"));
+    }
+
+    #[test]
+    fn test_repeated_pattern() {
+        // "the " occurs twice and hashes identically both times
+        let stream = b"the cat and the hat";
+        let mut rolling = RollingChecksum::<4>::new();
+        let mut hashes = [0u32; 19];
+        for (h, &b) in hashes.iter_mut().zip(stream.iter()) {
+            *h = rolling.push(b);
+        }
+        assert_eq!(hashes[3], hashes[15]);
+        assert_ne!(hashes[3], hashes[16]);
+    }
+}
@@ -15,7 +15,9 @@
 // with this program; if not, write to the Free Software Foundation, Inc.,
 // 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
 
+use crate::error::ArrayError;
 use core::cmp::Ordering;
+use core::fmt;
 
 /// Generic array min/max value and index calculation
 ///
@@ -32,7 +34,7 @@ fn is_valid<T: PartialOrd>(value: T) -> bool {
 }
 
 /// Generic comparison function (handles floating-point NaN correctly)
-fn compare_items<T: PartialOrd>(a: T, b: T) -> Ordering {
+pub(crate) fn compare_items<T: PartialOrd>(a: T, b: T) -> Ordering {
     match a.partial_cmp(&b) {
         Some(ordering) => ordering,
         None => {
@@ -48,6 +50,18 @@ fn compare_items<T: PartialOrd>(a: T, b: T) -> Ordering {
     }
 }
 
+/// Total-order variant of `compare_items` where two NaN values are `Equal`
+///
+/// Partitioning and ranking need every value, NaN included, to compare
+/// `Equal` to itself.
+pub(crate) fn compare_items_total<T: PartialOrd>(a: T, b: T) -> Ordering {
+    if !a.eq(&a) && !b.eq(&b) {
+        Ordering::Equal
+    } else {
+        compare_items(a, b)
+    }
+}
+
 /// Extremum result, containing value and index
 #[derive(Debug, PartialEq)]
 pub struct Extremum<T> {
@@ -62,6 +76,25 @@ pub struct ExtremumPair<T> {
     pub max: Extremum<T>,
 }
 
+/// Formats as `value@index`; format options such as precision apply to
+/// the value
+impl<T: fmt::Display> fmt::Display for Extremum<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)?;
+        write!(f, "@{}", self.index)
+    }
+}
+
+/// Formats as `min=value@index max=value@index`
+impl<T: fmt::Display> fmt::Display for ExtremumPair<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("min=")?;
+        self.min.fmt(f)?;
+        f.write_str(" max=")?;
+        self.max.fmt(f)
+    }
+}
+
 impl ArrayExtrema {
     /// Find both the minimum and maximum values and their indices in the array
     ///
@@ -194,9 +227,379 @@ impl ArrayExtrema {
     }
 }
 
+/// Count the inversions in the array
+///
+/// An inversion is an index pair `(i, j)` with `i < j` and `arr[i] > arr[j]`,
+/// so a sorted array has none and a reversed one has `n * (n - 1) / 2`.
+/// Runs in O(n²) without any scratch memory.
+///
+/// # Parameters
+/// - `arr`: The array slice to process
+///
+/// # Return value
+/// - The number of inversions
+pub fn count_inversions<T>(arr: &[T]) -> usize
+where
+    T: PartialOrd + Copy,
+{
+    let mut count = 0;
+
+    for (i, &a) in arr.iter().enumerate() {
+        for &b in &arr[i + 1..] {
+            if a > b {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Compute the 0-based rank of every element in the array
+///
+/// `ranks[i]` receives the rank of `arr[i]` among all elements (0 = smallest).
+/// Equal elements are ranked by their position, so the earliest one gets the
+/// lowest rank. NaN values are ordered as in `ArrayExtrema`.
+///
+/// # Parameters
+/// - `arr`: The array slice to process
+/// - `ranks`: Output buffer, must hold at least `arr.len()` entries
+///
+/// # Return value
+/// - `Ok(())`: The first `arr.len()` entries of `ranks` were filled
+/// - `Err`: `ranks` is shorter than `arr`
+pub fn rank_into<T>(arr: &[T], ranks: &mut [usize]) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    if ranks.len() < arr.len() {
+        return Err(ArrayError::BufferTooSmall);
+    }
+
+    for (i, &item) in arr.iter().enumerate() {
+        let mut rank = 0;
+        for (j, &other) in arr.iter().enumerate() {
+            if j == i {
+                continue;
+            }
+            // NaN ties with NaN, so repeated NaNs are ranked by position too
+            match compare_items_total(other, item) {
+                Ordering::Less => rank += 1,
+                Ordering::Equal if j < i => rank += 1,
+                _ => {}
+            }
+        }
+        ranks[i] = rank;
+    }
+
+    Ok(())
+}
+
+/// Pick between two values, preferring a valid one over NaN
+fn pick<T: PartialOrd + Copy>(a: T, b: T, keep: Ordering) -> T {
+    if !is_valid(a) {
+        b
+    } else if !is_valid(b) || compare_items(a, b) != keep.reverse() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Write the elementwise minimum of two arrays into `out`
+///
+/// NaN values lose against any valid value, as in `ArrayExtrema`.
+///
+/// # Parameters
+/// - `a`: The first array slice
+/// - `b`: The second array slice, must have the same length as `a`
+/// - `out`: Output buffer, must hold at least `a.len()` entries
+///
+/// # Return value
+/// - `Ok(())`: The first `a.len()` entries of `out` were filled
+/// - `Err`: The lengths of `a` and `b` differ or `out` is too small
+pub fn elementwise_min<T>(a: &[T], b: &[T], out: &mut [T]) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    elementwise(a, b, out, Ordering::Less)
+}
+
+/// Write the elementwise maximum of two arrays into `out`
+///
+/// See [`elementwise_min`] for the parameters and error cases.
+pub fn elementwise_max<T>(a: &[T], b: &[T], out: &mut [T]) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    elementwise(a, b, out, Ordering::Greater)
+}
+
+fn elementwise<T>(a: &[T], b: &[T], out: &mut [T], keep: Ordering) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    if a.len() != b.len() {
+        return Err(ArrayError::LengthMismatch);
+    }
+    if out.len() < a.len() {
+        return Err(ArrayError::BufferTooSmall);
+    }
+
+    for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+        *o = pick(x, y, keep);
+    }
+
+    Ok(())
+}
+
+/// Find the index of the first element equal to `value`
+///
+/// # Return value
+/// - `Some(usize)`: The earliest matching position
+/// - `None`: No element matches
+pub fn first_index_of<T: PartialEq>(arr: &[T], value: &T) -> Option<usize> {
+    arr.iter().position(|item| item == value)
+}
+
+/// Find the index of the last element equal to `value`
+///
+/// # Return value
+/// - `Some(usize)`: The latest matching position
+/// - `None`: No element matches
+pub fn last_index_of<T: PartialEq>(arr: &[T], value: &T) -> Option<usize> {
+    arr.iter().rposition(|item| item == value)
+}
+
+/// Write the running minimum of the array into `out`
+///
+/// `out[i]` receives the minimum of `arr[0..=i]`, giving a non-increasing
+/// envelope. NaN values are skipped once a valid value has been seen.
+///
+/// # Parameters
+/// - `arr`: The array slice to process
+/// - `out`: Output buffer, must hold at least `arr.len()` entries
+///
+/// # Return value
+/// - `Ok(())`: The first `arr.len()` entries of `out` were filled
+/// - `Err`: `out` is shorter than `arr`
+pub fn running_min<T>(arr: &[T], out: &mut [T]) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    running(arr, out, Ordering::Less)
+}
+
+/// Write the running maximum of the array into `out`
+///
+/// `out[i]` receives the maximum of `arr[0..=i]`, giving a non-decreasing
+/// envelope. See [`running_min`] for the parameters and error cases.
+pub fn running_max<T>(arr: &[T], out: &mut [T]) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    running(arr, out, Ordering::Greater)
+}
+
+fn running<T>(arr: &[T], out: &mut [T], keep: Ordering) -> Result<(), ArrayError>
+where
+    T: PartialOrd + Copy,
+{
+    if out.len() < arr.len() {
+        return Err(ArrayError::BufferTooSmall);
+    }
+
+    let mut current = match arr.first() {
+        Some(&first) => first,
+        None => return Ok(()),
+    };
+
+    for (o, &item) in out.iter_mut().zip(arr) {
+        current = pick(current, item, keep);
+        *o = current;
+    }
+
+    Ok(())
+}
+
+/// Find the index of the minimum value in the array
+///
+/// Same semantics as [`ArrayExtrema::min_with_index`] (NaN skipped, first
+/// occurrence wins) without building an `Extremum`.
+pub fn argmin<T>(arr: &[T]) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    arg_extremum(arr, Ordering::Less)
+}
+
+/// Find the index of the maximum value in the array
+///
+/// Same semantics as [`ArrayExtrema::max_with_index`] (NaN skipped, first
+/// occurrence wins) without building an `Extremum`.
+pub fn argmax<T>(arr: &[T]) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    arg_extremum(arr, Ordering::Greater)
+}
+
+fn arg_extremum<T>(arr: &[T], keep: Ordering) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    let mut best: Option<usize> = None;
+
+    for (i, &item) in arr.iter().enumerate() {
+        if !is_valid(item) {
+            continue;
+        }
+
+        match best {
+            Some(b) if compare_items(item, arr[b]) != keep => {}
+            _ => best = Some(i),
+        }
+    }
+
+    best
+}
+
+/// Select the elements with the largest projected keys
+///
+/// Writes up to `out.len()` elements into `out`, ordered by descending key.
+/// Elements with equal keys keep their original order and NaN keys rank
+/// below every valid key.
+///
+/// # Parameters
+/// - `arr`: The array slice to process
+/// - `key`: Projection applied to each element
+/// - `out`: Output buffer, its length is the `k` of the top-k
+///
+/// # Return value
+/// - The number of elements written, `min(arr.len(), out.len())`
+pub fn top_k_by_key<T, K, F>(arr: &[T], mut key: F, out: &mut [T]) -> usize
+where
+    T: Copy,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    let mut count = 0;
+
+    for item in arr {
+        let k = key(item);
+
+        // Position after every element whose key is not smaller
+        let mut pos = count;
+        while pos > 0 && compare_items(&key(&out[pos - 1]), &k) == Ordering::Less {
+            pos -= 1;
+        }
+
+        if count < out.len() {
+            count += 1;
+        } else if pos == count {
+            continue;
+        }
+
+        out.copy_within(pos..count - 1, pos + 1);
+        out[pos] = *item;
+    }
+
+    count
+}
+
+/// Fixed-capacity ring of indices used by the sliding window functions
+struct IndexDeque<const W: usize> {
+    data: [usize; W],
+    head: usize,
+    len: usize,
+}
+
+impl<const W: usize> IndexDeque<W> {
+    fn new() -> Self {
+        Self {
+            data: [0; W],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn front(&self) -> Option<usize> {
+        (self.len > 0).then(|| self.data[self.head])
+    }
+
+    fn back(&self) -> Option<usize> {
+        (self.len > 0).then(|| self.data[(self.head + self.len - 1) % W])
+    }
+
+    fn push_back(&mut self, idx: usize) {
+        self.data[(self.head + self.len) % W] = idx;
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) {
+        self.head = (self.head + 1) % W;
+        self.len -= 1;
+    }
+
+    fn pop_back(&mut self) {
+        self.len -= 1;
+    }
+
+    /// Push `i`, dropping indices that fell out of the window or can no
+    /// longer be the extremum selected by `keep`
+    fn slide<T: PartialOrd + Copy>(&mut self, arr: &[T], i: usize, keep: Ordering) {
+        if self.front().is_some_and(|f| f + W <= i) {
+            self.pop_front();
+        }
+        while self
+            .back()
+            .is_some_and(|b| compare_items(arr[i], arr[b]) != keep.reverse())
+        {
+            self.pop_back();
+        }
+        self.push_back(i);
+    }
+}
+
+/// Compute the range (max - min) of every width-`W` window of the array
+///
+/// Uses monotonic index deques so the whole pass is O(n).
+///
+/// # Parameters
+/// - `arr`: The array slice to process
+/// - `out`: Output buffer, `out[i]` receives the range of `arr[i..i + W]`
+///
+/// # Return value
+/// - The number of windows written, limited by `out.len()`
+pub fn moving_range<T, const W: usize>(arr: &[T], out: &mut [T]) -> usize
+where
+    T: PartialOrd + Copy + core::ops::Sub<Output = T>,
+{
+    if W == 0 || arr.len() < W {
+        return 0;
+    }
+
+    let windows = (arr.len() - W + 1).min(out.len());
+    let mut min_deque = IndexDeque::<W>::new();
+    let mut max_deque = IndexDeque::<W>::new();
+
+    for i in 0..windows + W - 1 {
+        min_deque.slide(arr, i, Ordering::Less);
+        max_deque.slide(arr, i, Ordering::Greater);
+
+        if i + 1 >= W {
+            let (min, max) = (min_deque.front().unwrap(), max_deque.front().unwrap());
+            out[i + 1 - W] = arr[max] - arr[min];
+        }
+    }
+
+    windows
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::TextBuf;
 
     #[test]
     fn test_signed_integers() {
@@ -345,4 +748,175 @@ mod tests {
         assert_eq!(result.max.value, 5);
         assert_eq!(result.max.index, 0); // Index of the first 5
     }
+
+    #[test]
+    fn test_count_inversions() {
+        assert_eq!(count_inversions(&[1, 2, 3, 4, 5]), 0);
+        assert_eq!(count_inversions(&[5, 4, 3, 2, 1]), 5 * 4 / 2);
+        assert_eq!(count_inversions(&[2, 1, 3, 1]), 3);
+        assert_eq!(count_inversions::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn test_rank_into() {
+        let arr = [30, 10, 20, 10, 30];
+        let mut ranks = [0; 5];
+        assert_eq!(rank_into(&arr, &mut ranks), Ok(()));
+        // Ties are broken by the earliest index
+        assert_eq!(ranks, [3, 0, 2, 1, 4]);
+
+        let mut small = [0; 4];
+        assert!(rank_into(&arr, &mut small).is_err());
+
+        let arr = [f32::NAN];
+        let mut ranks = [9; 1];
+        assert_eq!(rank_into(&arr, &mut ranks), Ok(()));
+        assert_eq!(ranks, [0]);
+
+        // NaNs rank lowest, in index order, and the ranks stay a permutation
+        let arr = [2.0, f32::NAN, 1.0, f32::NAN, f32::NAN];
+        let mut ranks = [0; 5];
+        assert_eq!(rank_into(&arr, &mut ranks), Ok(()));
+        assert_eq!(ranks, [4, 0, 3, 1, 2]);
+        let mut seen = [false; 5];
+        for &r in &ranks {
+            seen[r] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_elementwise_min_max() {
+        let a = [1.0, 5.0, f64::NAN, 4.0];
+        let b = [2.0, 3.0, 7.0, f64::NAN];
+        let mut out = [0.0; 4];
+
+        assert_eq!(elementwise_min(&a, &b, &mut out), Ok(()));
+        assert_eq!(out, [1.0, 3.0, 7.0, 4.0]);
+
+        assert_eq!(elementwise_max(&a, &b, &mut out), Ok(()));
+        assert_eq!(out, [2.0, 5.0, 7.0, 4.0]);
+
+        assert_eq!(
+            elementwise_min(&a, &b[..3], &mut out),
+            Err(ArrayError::LengthMismatch)
+        );
+        assert!(elementwise_max(&a, &b, &mut out[..2]).is_err());
+    }
+
+    #[test]
+    fn test_first_last_index_of() {
+        let arr = [4, 7, 1, 7, 3, 7, 2];
+        assert_eq!(first_index_of(&arr, &7), Some(1));
+        assert_eq!(last_index_of(&arr, &7), Some(5));
+        assert_eq!(first_index_of(&arr, &4), last_index_of(&arr, &4));
+        assert_eq!(first_index_of(&arr, &9), None);
+        assert_eq!(last_index_of(&arr, &9), None);
+    }
+
+    #[test]
+    fn test_running_min_max() {
+        let arr = [5, 3, 6, 2, 4, 1, 7];
+        let mut out = [0; 7];
+
+        assert_eq!(running_min(&arr, &mut out), Ok(()));
+        assert_eq!(out, [5, 3, 3, 2, 2, 1, 1]);
+        assert!(out.windows(2).all(|w| w[1] <= w[0]));
+
+        assert_eq!(running_max(&arr, &mut out), Ok(()));
+        assert_eq!(out, [5, 5, 6, 6, 6, 6, 7]);
+
+        assert!(running_min(&arr, &mut out[..6]).is_err());
+    }
+
+    #[test]
+    fn test_argmin_argmax() {
+        let arr = [5, 2, 5, 3, 2];
+        assert_eq!(argmin(&arr), Some(1));
+        assert_eq!(argmax(&arr), Some(0));
+        assert_eq!(
+            argmin(&arr),
+            ArrayExtrema::min_with_index(&arr).map(|m| m.index)
+        );
+        assert_eq!(
+            argmax(&arr),
+            ArrayExtrema::max_with_index(&arr).map(|m| m.index)
+        );
+
+        let arr = [f32::NAN, 1.5, f32::NAN, 0.5];
+        assert_eq!(argmin(&arr), Some(3));
+        assert_eq!(argmax(&arr), Some(1));
+
+        assert_eq!(argmin::<i32>(&[]), None);
+        assert_eq!(argmax(&[f64::NAN; 3]), None);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn test_top_k_by_key() {
+        let points = [
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 4.0, y: 1.0 },
+            Point { x: 2.0, y: 2.0 },
+            Point { x: 5.0, y: 3.0 },
+            Point { x: 4.0, y: 4.0 },
+            Point { x: 0.5, y: 5.0 },
+        ];
+        let mut out = [Point { x: 0.0, y: 0.0 }; 3];
+
+        assert_eq!(top_k_by_key(&points, |p| p.x, &mut out), 3);
+        assert_eq!(out, [points[3], points[1], points[4]]);
+
+        // Fewer elements than the buffer can hold
+        let mut out = [0; 4];
+        assert_eq!(top_k_by_key(&[3, 9, 1], |&v| v, &mut out), 3);
+        assert_eq!(out[..3], [9, 3, 1]);
+
+        assert_eq!(top_k_by_key(&[3, 9, 1], |&v| v, &mut []), 0);
+    }
+
+    #[test]
+    fn test_moving_range() {
+        let arr = [4, 8, 1, 9, 3, 3, 7, 2, 6, 5];
+        let mut out = [0; 10];
+
+        let written = moving_range::<_, 3>(&arr, &mut out);
+        assert_eq!(written, 8);
+        for (i, w) in arr.windows(3).enumerate() {
+            let max = *w.iter().max().unwrap();
+            let min = *w.iter().min().unwrap();
+            assert_eq!(out[i], max - min);
+        }
+
+        // Output buffer limits the number of windows
+        assert_eq!(moving_range::<_, 3>(&arr, &mut out[..2]), 2);
+        // Window wider than the array
+        assert_eq!(moving_range::<_, 11>(&arr, &mut out), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        use core::fmt::Write;
+
+        let arr = [-5, 10, 3, -8, 0];
+        let result = ArrayExtrema::min_max_with_indices(&arr).unwrap();
+        let mut buf = TextBuf::new();
+        write!(buf, "{}", result.min).unwrap();
+        assert_eq!(buf.as_str(), "-8@3");
+
+        let mut buf = TextBuf::new();
+        write!(buf, "{}", result).unwrap();
+        assert_eq!(buf.as_str(), "min=-8@3 max=10@1");
+
+        let arr = [1.25f32, 0.5, 2.0];
+        let result = ArrayExtrema::min_max_with_indices(&arr).unwrap();
+        let mut buf = TextBuf::new();
+        write!(buf, "{:.1}", result).unwrap();
+        assert_eq!(buf.as_str(), "min=0.5@1 max=2.0@2");
+    }
 }
@@ -0,0 +1,260 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Array-based double-ended queue implementation
+pub struct ArrayDeque<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    head: usize, // Index of the front element
+    len: usize,  // Current number of elements
+}
+
+impl<T, const N: usize> ArrayDeque<T, N> {
+    /// Create a new empty deque
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this deque type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` plus two `usize` counters.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Check if the deque is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the deque is full
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the current number of elements in the deque
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of elements the deque can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Map a logical position to a physical slot
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % N
+    }
+
+    /// Add an element to the back of the deque
+    /// Returns Err(element) if the deque is full
+    pub fn push_back(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+
+        let tail = self.slot(self.len);
+        self.data[tail].write(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Add an element to the front of the deque
+    /// Returns Err(element) if the deque is full
+    pub fn push_front(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+
+        self.head = (self.head + N - 1) % N;
+        self.data[self.head].write(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove an element from the front of the deque
+    /// Returns None if the deque is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // Safety: The front slot is initialized and is no longer tracked
+        let item = unsafe { self.data[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+
+    /// Remove an element from the back of the deque
+    /// Returns None if the deque is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        let tail = self.slot(self.len);
+        // Safety: The back slot is initialized and is no longer tracked
+        Some(unsafe { self.data[tail].assume_init_read() })
+    }
+
+    /// Peek at the front element
+    pub fn front(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // Safety: The front slot is initialized when the deque is not empty
+        Some(unsafe { self.data[self.head].assume_init_ref() })
+    }
+
+    /// Peek at the back element
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // Safety: The back slot is initialized when the deque is not empty
+        Some(unsafe { self.data[self.slot(self.len - 1)].assume_init_ref() })
+    }
+
+    /// Iterate over the elements from front to back
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        // Safety: Every logical position below `len` is initialized
+        (0..self.len).map(move |i| unsafe { self.data[self.slot(i)].assume_init_ref() })
+    }
+
+    /// Remove and drop all elements
+    pub fn clear(&mut self) {
+        let (head, len) = (self.head, self.len);
+        // Reset first so a panicking destructor cannot cause a double drop
+        self.head = 0;
+        self.len = 0;
+        for i in 0..len {
+            unsafe {
+                ptr::drop_in_place(self.data[(head + i) % N].as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drop handler to process initialized elements
+impl<T, const N: usize> Drop for ArrayDeque<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for ArrayDeque<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::DropCounter;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_basic_operations() {
+        let mut deque: ArrayDeque<i32, 3> = ArrayDeque::new();
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+
+        assert_eq!(deque.push_back(2), Ok(()));
+        assert_eq!(deque.push_front(1), Ok(()));
+        assert_eq!(deque.push_back(3), Ok(()));
+        assert!(deque.is_full());
+        assert_eq!(deque.push_front(0), Err(0)); // Deque is full
+
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert!(deque.iter().copied().eq([1, 2, 3]));
+
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        let mut deque: ArrayDeque<i32, 4> = ArrayDeque::new();
+
+        // Front pushes wrap below index 0 straight away
+        deque.push_front(2).unwrap();
+        deque.push_front(1).unwrap();
+        deque.push_back(3).unwrap();
+        assert!(deque.iter().copied().eq([1, 2, 3]));
+
+        // Cycle elements across the boundary in both directions
+        for round in 0..6 {
+            let v = deque.pop_front().unwrap();
+            deque.push_back(v + 10).unwrap();
+            if round % 2 == 1 {
+                let v = deque.pop_back().unwrap();
+                deque.push_front(v).unwrap();
+            }
+        }
+        assert!(deque.iter().copied().eq([21, 22, 23]));
+        assert_eq!(deque.len(), 3);
+
+        deque.push_front(0).unwrap();
+        assert_eq!(deque.back(), Some(&23));
+        assert_eq!(deque.front(), Some(&0));
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+        {
+            let mut deque: ArrayDeque<DropCounter, 3> = ArrayDeque::new();
+            assert!(deque.push_back(DropCounter(&drops)).is_ok());
+            assert!(deque.push_front(DropCounter(&drops)).is_ok());
+            assert!(deque.push_front(DropCounter(&drops)).is_ok());
+            drop(deque.pop_back());
+            assert_eq!(drops.get(), 1);
+            assert!(deque.push_back(DropCounter(&drops)).is_ok());
+            assert_eq!(drops.get(), 1);
+        }
+        // The three live elements straddle the wrap boundary
+        assert_eq!(drops.get(), 4);
+    }
+}
@@ -0,0 +1,87 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use core::fmt;
+
+/// Error type shared by the fallible operations of this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrayError {
+    /// The container has no free slot left
+    Full,
+    /// The container holds no elements
+    Empty,
+    /// An index or position is outside the valid range
+    InvalidIndex,
+    /// The operation needs more elements than the container holds
+    NotEnoughElements,
+    /// The target slot is already occupied
+    AlreadyExists,
+    /// The caller-provided output buffer is shorter than required
+    BufferTooSmall,
+    /// Two input slices that must be the same length are not
+    LengthMismatch,
+    /// Serialized input or internal links are truncated or inconsistent
+    InvalidData,
+}
+
+impl ArrayError {
+    /// Get a short human-readable description of the error
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ArrayError::Full => "Container full",
+            ArrayError::Empty => "Container empty",
+            ArrayError::InvalidIndex => "Index out of range",
+            ArrayError::NotEnoughElements => "Not enough elements",
+            ArrayError::AlreadyExists => "Slot already occupied",
+            ArrayError::BufferTooSmall => "Output buffer too small",
+            ArrayError::LengthMismatch => "Length mismatch",
+            ArrayError::InvalidData => "Invalid data",
+        }
+    }
+}
+
+impl fmt::Display for ArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for ArrayError {}
+
+impl From<ArrayError> for &'static str {
+    fn from(err: ArrayError) -> Self {
+        err.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TextBuf;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_display_and_conversion() {
+        let mut buf = TextBuf::new();
+        write!(buf, "{}", ArrayError::Full).unwrap();
+        assert_eq!(buf.as_str(), "Container full");
+
+        let msg: &'static str = ArrayError::LengthMismatch.into();
+        assert_eq!(msg, "Length mismatch");
+        assert_eq!(ArrayError::Empty.as_str(), "Container empty");
+    }
+}
@@ -15,58 +15,1623 @@
 // with this program; if not, write to the Free Software Foundation, Inc.,
 // 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
 
-/// Moving average filter structure
+use crate::math::sqrt_f32;
+use core::ops::{Add, Mul, Sub};
+
+/// Numeric sample type accepted by the generic filters
+///
+/// Implemented for `f32`, `f64` and the primitive integer types up to 64
+/// bits. Integer averages are truncated towards zero. `i128` and `u128` are
+/// left out because no wider type exists to accumulate their sums in.
+pub trait Sample:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// Wider type used to accumulate sums of samples
+    ///
+    /// Wide enough to hold the sum of any realistic number of samples, so a
+    /// window full of `MAX` values neither overflows nor truncates.
+    type Acc: Copy + Add<Output = Self::Acc> + Sub<Output = Self::Acc>;
+
+    /// Additive identity
+    const ZERO: Self;
+
+    /// Additive identity of the accumulator
+    const ACC_ZERO: Self::Acc;
+
+    /// Convert the sample into the accumulator type
+    fn widen(self) -> Self::Acc;
+
+    /// Divide an accumulated sum by a number of samples
+    ///
+    /// The division happens in the accumulator type, so the result always
+    /// fits back into `Self`.
+    fn mean(sum: Self::Acc, count: usize) -> Self;
+
+    /// Convert an accumulated sum back, saturating at the range of `Self`
+    fn narrow(sum: Self::Acc) -> Self;
+
+    /// Convert the sample to `f64` for statistics that need a fractional result
+    fn to_f64(self) -> f64;
+
+    /// Check that the sample is usable (not NaN or infinite)
+    fn is_valid_sample(self) -> bool;
+}
+
+macro_rules! impl_sample {
+    ($zero:expr, $valid:expr, $narrow:expr => $($t:ty => $acc:ty),*) => {
+        $(
+            impl Sample for $t {
+                type Acc = $acc;
+
+                const ZERO: Self = $zero;
+                const ACC_ZERO: $acc = $zero;
+
+                fn widen(self) -> $acc {
+                    self as $acc
+                }
+
+                fn mean(sum: $acc, count: usize) -> Self {
+                    (sum / count as $acc) as $t
+                }
+
+                fn narrow(sum: $acc) -> Self {
+                    $narrow(sum)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn is_valid_sample(self) -> bool {
+                    $valid(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_sample!(0.0, |v: Self| v.is_finite(), |s| s as Self => f32 => f64, f64 => f64);
+impl_sample!(
+    0,
+    |_| true,
+    |s| Self::try_from(s).unwrap_or(if s > Self::ZERO.widen() { Self::MAX } else { Self::MIN }) =>
+    i8 => i64, i16 => i64, i32 => i64, i64 => i128, isize => i128,
+    u8 => u64, u16 => u64, u32 => u64, u64 => u128, usize => u128);
+
+/// Common interface of the single-input `f32` filters
+pub trait Filter {
+    /// Add a new value and return the filtered output
+    fn add(&mut self, value: f32) -> f32;
+
+    /// Get the current output without adding a new value
+    fn value(&self) -> f32;
+
+    /// Reset the filter state
+    fn reset(&mut self);
+
+    /// Wrap the filter so its output is clamped to `[min, max]`
+    fn with_limits(self, min: f32, max: f32) -> Saturating<Self>
+    where
+        Self: Sized,
+    {
+        Saturating::new(self, min, max)
+    }
+}
+
+/// Moving average filter structure, generic over the sample type
+///
+/// The running sum is updated incrementally and recomputed from the buffer
+/// each time the window wraps around. That costs one O(N) pass every `N`
+/// samples, but keeps floating-point rounding from accumulating over long
+/// runs so the average never drifts from the true windowed mean.
+///
+/// Like every windowed filter in this module, a zero-length window is
+/// rejected at compile time:
+///
+/// ```compile_fail
+/// use arraypp::filter::MovingAverage;
+///
+/// let filter = MovingAverage::<0>::new();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GenericMovingAverage<T: Sample, const N: usize> {
+    buffer: [T; N],
+    index: usize,
+    count: usize,
+    sum: T::Acc,
+}
+
+/// Moving average filter over `f32` samples
+pub type MovingAverage<const N: usize> = GenericMovingAverage<f32, N>;
+
+impl<T: Sample, const N: usize> GenericMovingAverage<T, N> {
+    /// Create a new moving average filter
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            buffer: [T::ZERO; N],
+            index: 0,
+            count: 0,
+            sum: T::ACC_ZERO,
+        }
+    }
+
+    /// Add a new value and return the current average
+    pub fn add(&mut self, value: T) -> T {
+        if self.count < N {
+            // Buffer is not full yet
+            self.sum = self.sum + value.widen();
+            self.buffer[self.count] = value;
+            self.count += 1;
+        } else {
+            // Buffer is full, replace the oldest value
+            self.sum = self.sum - self.buffer[self.index].widen() + value.widen();
+            self.buffer[self.index] = value;
+            self.index = (self.index + 1) % N;
+
+            if self.index == 0 {
+                // Discard the rounding error accumulated over the last N updates
+                self.sum = self
+                    .buffer
+                    .iter()
+                    .fold(T::ACC_ZERO, |acc, &v| acc + v.widen());
+            }
+        }
+
+        T::mean(self.sum, self.count)
+    }
+
+    /// Add a new value unless it is NaN or infinite
+    ///
+    /// Invalid values would poison the running sum, so they are discarded
+    /// without touching the window.
+    ///
+    /// # Return value
+    /// - `Some(T)`: The value was accepted, holds the new average
+    /// - `None`: The value was rejected
+    pub fn add_checked(&mut self, value: T) -> Option<T> {
+        if value.is_valid_sample() {
+            Some(self.add(value))
+        } else {
+            None
+        }
+    }
+
+    /// Get the current average without adding a new value
+    pub fn average(&self) -> T {
+        if self.count == 0 {
+            T::ZERO
+        } else {
+            T::mean(self.sum, self.count)
+        }
+    }
+
+    /// Get the running total of the buffered values
+    ///
+    /// Integer totals that do not fit into `T` saturate at its range, see
+    /// [`wide_sum`](Self::wide_sum) for the exact value.
+    pub fn sum(&self) -> T {
+        T::narrow(self.sum)
+    }
+
+    /// Get the running total in the wider accumulator type, see [`Sample::Acc`]
+    pub fn wide_sum(&self) -> T::Acc {
+        self.sum
+    }
+
+    /// Get the most recently added buffered value
+    pub fn newest(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.buffer[(self.index + self.count - 1) % N])
+        }
+    }
+
+    /// Get the least recently added buffered value
+    pub fn oldest(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.buffer[self.index])
+        }
+    }
+
+    /// Population variance of the buffered values
+    ///
+    /// Computed in `f64` around the exact (untruncated) mean, so integer
+    /// samples of any magnitude neither overflow nor lose the fraction.
+    /// Returns zero while fewer than two values are buffered.
+    pub fn variance(&self) -> f32 {
+        if self.count < 2 {
+            return 0.0;
+        }
+
+        let count = self.count as f64;
+        let mean = self.iter().map(T::to_f64).sum::<f64>() / count;
+        let sum_sq: f64 = self
+            .iter()
+            .map(|v| {
+                let diff = v.to_f64() - mean;
+                diff * diff
+            })
+            .sum();
+        (sum_sq / count) as f32
+    }
+
+    /// Iterate over the buffered values from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        // `index` is the oldest slot once the buffer has wrapped, 0 before that
+        (0..self.count).map(move |i| self.buffer[(self.index + i) % N])
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = T::ACC_ZERO;
+    }
+
+    /// Reset the filter state and seed it with a single sample
+    pub fn reset_with(&mut self, value: T) {
+        self.reset();
+        self.add(value);
+    }
+
+    /// Get the number of valid data points
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if the filter is empty
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Check if the window holds `N` samples
+    pub fn is_full(&self) -> bool {
+        self.count == N
+    }
+}
+
+impl<T: Sample, const N: usize> Default for GenericMovingAverage<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Filter for MovingAverage<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        GenericMovingAverage::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        self.average()
+    }
+
+    fn reset(&mut self) {
+        GenericMovingAverage::reset(self)
+    }
+}
+
+/// Median of a scratch buffer, which is partially reordered in the process
+///
+/// Only the lower half is selection-sorted, which is enough to read the
+/// middle element(s).
+fn median_of(scratch: &mut [f32]) -> f32 {
+    let len = scratch.len();
+    if len == 0 {
+        return 0.0;
+    }
+
+    let mid = len / 2;
+    for i in 0..=mid {
+        let mut smallest = i;
+        for j in i + 1..len {
+            if scratch[j].total_cmp(&scratch[smallest]).is_lt() {
+                smallest = j;
+            }
+        }
+        scratch.swap(i, smallest);
+    }
+
+    if len % 2 == 1 {
+        scratch[mid]
+    } else {
+        (scratch[mid - 1] + scratch[mid]) / 2.0
+    }
+}
+
+/// Sliding median filter structure
+///
+/// Rejects short impulse spikes that would drag a moving average along.
+#[derive(Debug, Clone, Copy)]
+pub struct MedianFilter<const N: usize> {
+    buffer: [f32; N],
+    index: usize,
+    count: usize,
+}
+
+impl<const N: usize> MedianFilter<N> {
+    /// Create a new median filter
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            buffer: [0.0; N],
+            index: 0,
+            count: 0,
+        }
+    }
+
+    /// Add a new value and return the median of the buffered values
+    pub fn add(&mut self, value: f32) -> f32 {
+        if self.count < N {
+            self.buffer[self.count] = value;
+            self.count += 1;
+        } else {
+            self.buffer[self.index] = value;
+            self.index = (self.index + 1) % N;
+        }
+
+        self.median()
+    }
+
+    /// Get the current median without adding a new value
+    pub fn median(&self) -> f32 {
+        let mut scratch = self.buffer;
+        median_of(&mut scratch[..self.count])
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+    }
+
+    /// Get the number of valid data points
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if the filter is empty
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl<const N: usize> Default for MedianFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Filter for MedianFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        MedianFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        self.median()
+    }
+
+    fn reset(&mut self) {
+        MedianFilter::reset(self)
+    }
+}
+
+/// Monotonic deque of the window candidates shared by `MinFilter` and `MaxFilter`
+///
+/// Holds `(value, sample number)` pairs whose values are monotonic from front
+/// to back, so the front is always the extremum of the last `N` samples.
+#[derive(Debug, Clone, Copy)]
+struct MonotonicWindow<const N: usize> {
+    values: [f32; N],
+    stamps: [usize; N],
+    head: usize,
+    len: usize,
+    samples: usize,
+}
+
+impl<const N: usize> MonotonicWindow<N> {
+    const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            values: [0.0; N],
+            stamps: [0; N],
+            head: 0,
+            len: 0,
+            samples: 0,
+        }
+    }
+
+    /// Push a value, evicting candidates it `dominates`, and return the front
+    fn add(&mut self, value: f32, dominates: fn(f32, f32) -> bool) -> f32 {
+        // Drop the front once it has left the window. Stamps are compared by
+        // wrapping distance so the sample counter may roll over.
+        if self.len > 0 && self.samples.wrapping_sub(self.stamps[self.head]) >= N {
+            self.head = (self.head + 1) % N;
+            self.len -= 1;
+        }
+
+        // Drop back candidates that can no longer be the extremum
+        while self.len > 0 {
+            let back = (self.head + self.len - 1) % N;
+            if !dominates(value, self.values[back]) {
+                break;
+            }
+            self.len -= 1;
+        }
+
+        let slot = (self.head + self.len) % N;
+        self.values[slot] = value;
+        self.stamps[slot] = self.samples;
+        self.len += 1;
+        self.samples = self.samples.wrapping_add(1);
+
+        self.values[self.head]
+    }
+
+    fn value(&self) -> f32 {
+        if self.len == 0 {
+            0.0
+        } else {
+            self.values[self.head]
+        }
+    }
+
+    fn reset(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.samples = 0;
+    }
+}
+
+/// Sliding minimum filter structure
+///
+/// Reports the minimum of the last `N` samples in O(1) amortized time.
+#[derive(Debug, Clone, Copy)]
+pub struct MinFilter<const N: usize> {
+    window: MonotonicWindow<N>,
+}
+
+impl<const N: usize> MinFilter<N> {
+    /// Create a new minimum filter
+    pub const fn new() -> Self {
+        Self {
+            window: MonotonicWindow::new(),
+        }
+    }
+
+    /// Add a new value and return the minimum of the last `N` values
+    pub fn add(&mut self, value: f32) -> f32 {
+        self.window.add(value, |new, old| new <= old)
+    }
+
+    /// Get the current minimum without adding a new value
+    pub fn value(&self) -> f32 {
+        self.window.value()
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<const N: usize> Default for MinFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Filter for MinFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        MinFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        MinFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        MinFilter::reset(self)
+    }
+}
+
+/// Sliding maximum filter structure
+///
+/// Reports the maximum of the last `N` samples in O(1) amortized time.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxFilter<const N: usize> {
+    window: MonotonicWindow<N>,
+}
+
+impl<const N: usize> MaxFilter<N> {
+    /// Create a new maximum filter
+    pub const fn new() -> Self {
+        Self {
+            window: MonotonicWindow::new(),
+        }
+    }
+
+    /// Add a new value and return the maximum of the last `N` values
+    pub fn add(&mut self, value: f32) -> f32 {
+        self.window.add(value, |new, old| new >= old)
+    }
+
+    /// Get the current maximum without adding a new value
+    pub fn value(&self) -> f32 {
+        self.window.value()
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<const N: usize> Default for MaxFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Filter for MaxFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        MaxFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        MaxFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        MaxFilter::reset(self)
+    }
+}
+
+/// Streaming mean and variance tracker
+///
+/// Uses Welford's online algorithm, which stays numerically stable without
+/// buffering any samples.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunningStats {
+    count: usize,
+    mean: f32,
+    m2: f32,
+}
+
+impl RunningStats {
+    /// Create a new statistics tracker
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Add a new sample
+    pub fn add(&mut self, value: f32) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / (self.count as f32);
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Mean of the samples seen so far
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    /// Population variance of the samples seen so far
+    pub fn variance(&self) -> f32 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count as f32)
+        }
+    }
+
+    /// Population standard deviation of the samples seen so far
+    pub fn std_dev(&self) -> f32 {
+        sqrt_f32(self.variance())
+    }
+
+    /// Number of samples seen so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Reset the tracker state
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// One-dimensional Kalman filter for a constant or slowly drifting value
+#[derive(Debug, Clone, Copy)]
+pub struct KalmanFilter1D {
+    process_noise: f32,
+    measurement_noise: f32,
+    estimate: f32,
+    covariance: f32,
+    initial_estimate: f32,
+    initial_covariance: f32,
+}
+
+impl KalmanFilter1D {
+    /// Create a new Kalman filter
+    ///
+    /// # Parameters
+    /// - `process_noise`: Variance added to the estimate on every step
+    /// - `measurement_noise`: Variance of the sensor readings
+    /// - `initial_estimate`: Starting estimate
+    /// - `initial_covariance`: Uncertainty of the starting estimate
+    pub const fn new(
+        process_noise: f32,
+        measurement_noise: f32,
+        initial_estimate: f32,
+        initial_covariance: f32,
+    ) -> Self {
+        Self {
+            process_noise,
+            measurement_noise,
+            estimate: initial_estimate,
+            covariance: initial_covariance,
+            initial_estimate,
+            initial_covariance,
+        }
+    }
+
+    /// Run a predict/update cycle with a new measurement and return the estimate
+    pub fn update(&mut self, measurement: f32) -> f32 {
+        // Predict
+        self.covariance += self.process_noise;
+
+        // Update
+        let gain = self.covariance / (self.covariance + self.measurement_noise);
+        self.estimate += gain * (measurement - self.estimate);
+        self.covariance *= 1.0 - gain;
+
+        self.estimate
+    }
+
+    /// Get the current estimate
+    pub fn estimate(&self) -> f32 {
+        self.estimate
+    }
+
+    /// Get the current estimate covariance
+    pub fn covariance(&self) -> f32 {
+        self.covariance
+    }
+
+    /// Reset the filter to its initial estimate and covariance
+    pub fn reset(&mut self) {
+        self.estimate = self.initial_estimate;
+        self.covariance = self.initial_covariance;
+    }
+}
+
+impl Filter for KalmanFilter1D {
+    fn add(&mut self, value: f32) -> f32 {
+        self.update(value)
+    }
+
+    fn value(&self) -> f32 {
+        self.estimate()
+    }
+
+    fn reset(&mut self) {
+        KalmanFilter1D::reset(self)
+    }
+}
+
+/// First-order low-pass IIR filter structure
+///
+/// The output is seeded with the first sample to avoid a startup transient
+/// from zero.
+#[derive(Debug, Clone, Copy)]
+pub struct LowPassFilter {
+    alpha: f32,
+    value: f32,
+    initialized: bool,
+}
+
+impl LowPassFilter {
+    /// Create a new low-pass filter
+    ///
+    /// # Parameters
+    /// - `cutoff_hz`: -3 dB cutoff frequency
+    /// - `sample_rate_hz`: Rate at which `add` is called
+    pub fn new(cutoff_hz: f32, sample_rate_hz: f32) -> Self {
+        let rc = 1.0 / (2.0 * core::f32::consts::PI * cutoff_hz);
+        let dt = 1.0 / sample_rate_hz;
+        Self {
+            alpha: dt / (rc + dt),
+            value: 0.0,
+            initialized: false,
+        }
+    }
+
+    /// Add a new value and return the filtered output
+    pub fn add(&mut self, value: f32) -> f32 {
+        if self.initialized {
+            self.value += self.alpha * (value - self.value);
+        } else {
+            self.value = value;
+            self.initialized = true;
+        }
+
+        self.value
+    }
+
+    /// Get the current output without adding a new value
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.value = 0.0;
+        self.initialized = false;
+    }
+}
+
+impl Filter for LowPassFilter {
+    fn add(&mut self, value: f32) -> f32 {
+        LowPassFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        LowPassFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        LowPassFilter::reset(self)
+    }
+}
+
+/// Cumulative moving average filter structure
+///
+/// Tracks the mean of every sample seen so far without storing them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CumulativeAverage {
+    mean: f32,
+    count: usize,
+}
+
+impl CumulativeAverage {
+    /// Create a new cumulative average filter
+    pub const fn new() -> Self {
+        Self {
+            mean: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Add a new value and return the mean of all values so far
+    pub fn add(&mut self, value: f32) -> f32 {
+        self.count += 1;
+        self.mean += (value - self.mean) / (self.count as f32);
+        self.mean
+    }
+
+    /// Get the current mean without adding a new value
+    pub fn value(&self) -> f32 {
+        self.mean
+    }
+
+    /// Number of values seen so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Filter for CumulativeAverage {
+    fn add(&mut self, value: f32) -> f32 {
+        CumulativeAverage::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        CumulativeAverage::value(self)
+    }
+
+    fn reset(&mut self) {
+        CumulativeAverage::reset(self)
+    }
+}
+
+/// Outlier-rejecting filter based on the median absolute deviation (MAD)
+///
+/// Each new sample is compared against the last `N` raw samples. It is
+/// rejected, and the previously accepted value returned instead, when it
+/// lies more than `threshold` MADs away from the window median. Every sample
+/// enters the window either way, so a lasting level change is accepted once
+/// it makes up most of the window. Samples are always accepted until the
+/// window has filled.
+///
+/// NaN and infinite samples are always rejected and never enter the window.
+///
+/// A window of identical samples has a MAD of zero, so any sample that
+/// differs from them, however slightly, counts as an outlier. Quantized
+/// inputs that sit on one value for a while will therefore hold their output
+/// until a new level fills most of the window.
+#[derive(Debug, Clone, Copy)]
+pub struct OutlierFilter<const N: usize> {
+    buffer: [f32; N],
+    index: usize,
+    count: usize,
+    threshold: f32,
+    last: f32,
+}
+
+impl<const N: usize> OutlierFilter<N> {
+    /// Create a new outlier filter rejecting samples beyond `threshold` MADs
+    pub const fn new(threshold: f32) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            buffer: [0.0; N],
+            index: 0,
+            count: 0,
+            threshold,
+            last: 0.0,
+        }
+    }
+
+    /// Add a new value and return it, or the last accepted value if it is an outlier
+    pub fn add(&mut self, value: f32) -> f32 {
+        // Would poison the median and MAD of every later window
+        if !value.is_finite() {
+            return self.last;
+        }
+
+        if self.count < N || !self.is_outlier(value) {
+            self.last = value;
+        }
+
+        if self.count < N {
+            self.buffer[self.count] = value;
+            self.count += 1;
+        } else {
+            self.buffer[self.index] = value;
+            self.index = (self.index + 1) % N;
+        }
+
+        self.last
+    }
+
+    /// Check a value against the median and MAD of the current window
+    fn is_outlier(&self, value: f32) -> bool {
+        let mut scratch = self.buffer;
+        let median = median_of(&mut scratch[..self.count]);
+        for v in &mut scratch[..self.count] {
+            *v = (*v - median).abs();
+        }
+        let mad = median_of(&mut scratch[..self.count]);
+
+        (value - median).abs() > self.threshold * mad
+    }
+
+    /// Get the last accepted value
+    pub fn value(&self) -> f32 {
+        self.last
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.last = 0.0;
+    }
+}
+
+impl<const N: usize> Filter for OutlierFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        OutlierFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        OutlierFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        OutlierFilter::reset(self)
+    }
+}
+
+/// Root-mean-square filter structure
+///
+/// Keeps a moving average of the squared samples, so the sum of squares is
+/// maintained incrementally like `MovingAverage` does for the sum.
+#[derive(Debug, Clone, Copy)]
+pub struct RmsFilter<const N: usize> {
+    squares: MovingAverage<N>,
+}
+
+impl<const N: usize> RmsFilter<N> {
+    /// Create a new RMS filter
+    pub const fn new() -> Self {
+        Self {
+            squares: MovingAverage::new(),
+        }
+    }
+
+    /// Add a new value and return the RMS of the last `N` values
+    pub fn add(&mut self, value: f32) -> f32 {
+        self.squares.add(value * value);
+        self.value()
+    }
+
+    /// Get the current RMS without adding a new value
+    pub fn value(&self) -> f32 {
+        // Clamp tiny negative rounding residue before taking the root
+        sqrt_f32(self.squares.average().max(0.0))
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.squares.reset();
+    }
+}
+
+impl<const N: usize> Default for RmsFilter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Filter for RmsFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        RmsFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        RmsFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        RmsFilter::reset(self)
+    }
+}
+
+/// Finite impulse response filter structure
+///
+/// Convolves the last `N` inputs with `N` coefficients, where `taps[0]`
+/// weights the newest input. The delay line starts out zeroed.
+#[derive(Debug, Clone, Copy)]
+pub struct FirFilter<const N: usize> {
+    taps: [f32; N],
+    delay: [f32; N],
+    index: usize,
+    output: f32,
+}
+
+impl<const N: usize> FirFilter<N> {
+    /// Create a new FIR filter with the given coefficients
+    pub const fn new(taps: [f32; N]) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            taps,
+            delay: [0.0; N],
+            index: 0,
+            output: 0.0,
+        }
+    }
+
+    /// Add a new value and return the filter output
+    pub fn add(&mut self, value: f32) -> f32 {
+        self.delay[self.index] = value;
+
+        let mut acc = 0.0;
+        for (k, tap) in self.taps.iter().enumerate() {
+            acc += tap * self.delay[(self.index + N - k) % N];
+        }
+
+        self.index = (self.index + 1) % N;
+        self.output = acc;
+        acc
+    }
+
+    /// Get the last output without adding a new value
+    pub fn value(&self) -> f32 {
+        self.output
+    }
+
+    /// Reset the delay line
+    pub fn reset(&mut self) {
+        self.delay = [0.0; N];
+        self.index = 0;
+        self.output = 0.0;
+    }
+}
+
+impl<const N: usize> Filter for FirFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        FirFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        FirFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        FirFilter::reset(self)
+    }
+}
+
+/// Debounce filter for digital inputs
+///
+/// The reported state only changes once the raw input has held the new
+/// level for `stable_samples` consecutive updates.
+#[derive(Debug, Clone, Copy)]
+pub struct Debounce {
+    stable_samples: u32,
+    counter: u32,
+    state: bool,
+}
+
+impl Debounce {
+    /// Create a new debounce filter, initially reporting `false`
+    pub const fn new(stable_samples: u32) -> Self {
+        Self {
+            stable_samples,
+            counter: 0,
+            state: false,
+        }
+    }
+
+    /// Feed a raw sample and return the debounced state
+    pub fn update(&mut self, raw: bool) -> bool {
+        if raw == self.state {
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+            if self.counter >= self.stable_samples {
+                self.state = raw;
+                self.counter = 0;
+            }
+        }
+
+        self.state
+    }
+
+    /// Get the debounced state
+    pub fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Reset the filter to report `false`
+    pub fn reset(&mut self) {
+        self.counter = 0;
+        self.state = false;
+    }
+}
+
+/// Schmitt trigger (hysteresis) filter structure
+///
+/// Switches on above `high` and back off only below `low`, so noise around
+/// a single threshold does not make the output chatter.
+#[derive(Debug, Clone, Copy)]
+pub struct Hysteresis {
+    low: f32,
+    high: f32,
+    state: bool,
+}
+
+impl Hysteresis {
+    /// Create a new hysteresis filter, initially reporting `false`
+    pub const fn new(low: f32, high: f32) -> Self {
+        Self {
+            low,
+            high,
+            state: false,
+        }
+    }
+
+    /// Feed a new value and return the output state
+    pub fn update(&mut self, value: f32) -> bool {
+        if self.state {
+            if value < self.low {
+                self.state = false;
+            }
+        } else if value > self.high {
+            self.state = true;
+        }
+
+        self.state
+    }
+
+    /// Get the output state
+    pub fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Reset the filter to report `false`
+    pub fn reset(&mut self) {
+        self.state = false;
+    }
+}
+
+/// Slew-rate limiter structure
+///
+/// Moves the output towards each new target by at most `max_delta_per_step`
+/// per call. The output starts at zero.
+#[derive(Debug, Clone, Copy)]
+pub struct SlewRateLimiter {
+    max_delta: f32,
+    output: f32,
+}
+
+impl SlewRateLimiter {
+    /// Create a new slew-rate limiter
+    ///
+    /// Only the magnitude of `max_delta_per_step` is used, so a negative
+    /// limit behaves like its positive counterpart.
+    ///
+    /// # Panics
+    /// Panics if `max_delta_per_step` is NaN.
+    pub const fn new(max_delta_per_step: f32) -> Self {
+        assert!(!max_delta_per_step.is_nan(), "Invalid slew rate");
+        Self {
+            max_delta: max_delta_per_step.abs(),
+            output: 0.0,
+        }
+    }
+
+    /// Step towards a new target value and return the limited output
+    pub fn add(&mut self, value: f32) -> f32 {
+        let delta = (value - self.output).clamp(-self.max_delta, self.max_delta);
+        self.output += delta;
+        self.output
+    }
+
+    /// Get the current output without stepping
+    pub fn value(&self) -> f32 {
+        self.output
+    }
+
+    /// Jump the output directly to `value`
+    pub fn reset_to(&mut self, value: f32) {
+        self.output = value;
+    }
+}
+
+impl Filter for SlewRateLimiter {
+    fn add(&mut self, value: f32) -> f32 {
+        SlewRateLimiter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        SlewRateLimiter::value(self)
+    }
+
+    fn reset(&mut self) {
+        self.reset_to(0.0)
+    }
+}
+
+/// Integrator accumulator structure
+///
+/// Uses the rectangle rule (`sum += value * dt`) by default, or the
+/// trapezoidal rule when created with `trapezoidal`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Integrator {
+    sum: f32,
+    previous: Option<f32>,
+    trapezoidal: bool,
+}
+
+impl Integrator {
+    /// Create a new rectangle-rule integrator
+    pub const fn new() -> Self {
+        Self {
+            sum: 0.0,
+            previous: None,
+            trapezoidal: false,
+        }
+    }
+
+    /// Create a new trapezoidal-rule integrator
+    pub const fn trapezoidal() -> Self {
+        Self {
+            sum: 0.0,
+            previous: None,
+            trapezoidal: true,
+        }
+    }
+
+    /// Integrate a new value over `dt` and return the running integral
+    pub fn add(&mut self, value: f32, dt: f32) -> f32 {
+        let height = match self.previous {
+            Some(previous) if self.trapezoidal => (previous + value) / 2.0,
+            _ => value,
+        };
+        self.sum += height * dt;
+        self.previous = Some(value);
+        self.sum
+    }
+
+    /// Get the running integral
+    pub fn value(&self) -> f32 {
+        self.sum
+    }
+
+    /// Reset the integral to zero
+    pub fn reset(&mut self) {
+        self.sum = 0.0;
+        self.previous = None;
+    }
+}
+
+/// Alpha-beta tracking filter structure
+///
+/// Estimates position and velocity of a constant-velocity target from noisy
+/// position measurements. The first measurement seeds the position.
+#[derive(Debug, Clone, Copy)]
+pub struct AlphaBetaFilter {
+    alpha: f32,
+    beta: f32,
+    position: f32,
+    velocity: f32,
+    initialized: bool,
+}
+
+impl AlphaBetaFilter {
+    /// Create a new alpha-beta filter with the given gains
+    pub const fn new(alpha: f32, beta: f32) -> Self {
+        Self {
+            alpha,
+            beta,
+            position: 0.0,
+            velocity: 0.0,
+            initialized: false,
+        }
+    }
+
+    /// Feed a position measurement taken `dt` after the previous one
+    ///
+    /// Returns the updated `(position, velocity)` estimate.
+    pub fn update(&mut self, measurement: f32, dt: f32) -> (f32, f32) {
+        if !self.initialized {
+            self.position = measurement;
+            self.initialized = true;
+            return (self.position, self.velocity);
+        }
+
+        let predicted = self.position + self.velocity * dt;
+        let residual = measurement - predicted;
+        self.position = predicted + self.alpha * residual;
+        self.velocity += self.beta * residual / dt;
+
+        (self.position, self.velocity)
+    }
+
+    /// Get the position estimate
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Get the velocity estimate
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Reset the filter state
+    pub fn reset(&mut self) {
+        self.position = 0.0;
+        self.velocity = 0.0;
+        self.initialized = false;
+    }
+}
+
+/// Complementary filter structure for sensor fusion
+///
+/// Blends a responsive but drifting estimate (e.g. an integrated gyro
+/// angle) with a noisy but stable one (e.g. an accelerometer angle) as
+/// `alpha * fast + (1 - alpha) * slow`.
+#[derive(Debug, Clone, Copy)]
+pub struct ComplementaryFilter {
+    alpha: f32,
+    output: f32,
+}
+
+impl ComplementaryFilter {
+    /// Create a new complementary filter weighting the fast input by `alpha`
+    pub const fn new(alpha: f32) -> Self {
+        Self { alpha, output: 0.0 }
+    }
+
+    /// Blend a new pair of estimates and return the fused value
+    pub fn update(&mut self, fast: f32, slow: f32) -> f32 {
+        self.output = self.alpha * fast + (1.0 - self.alpha) * slow;
+        self.output
+    }
+
+    /// Get the last fused value
+    pub fn value(&self) -> f32 {
+        self.output
+    }
+
+    /// Reset the fused value to zero
+    pub fn reset(&mut self) {
+        self.output = 0.0;
+    }
+}
+
+/// Output-clamping wrapper around another filter
+///
+/// The wrapped filter still sees every raw sample; only the value handed
+/// back is limited, so its internal state is unaffected by the clamp.
+#[derive(Debug, Clone, Copy)]
+pub struct Saturating<F> {
+    inner: F,
+    min: f32,
+    max: f32,
+}
+
+impl<F: Filter> Saturating<F> {
+    /// Wrap `inner`, clamping its output to `[min, max]`
+    ///
+    /// # Panics
+    /// Panics if `min > max` or either limit is NaN.
+    pub fn new(inner: F, min: f32, max: f32) -> Self {
+        assert!(min <= max, "Invalid output limits");
+        Self { inner, min, max }
+    }
+
+    /// Get a reference to the wrapped filter
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Unwrap and return the inner filter
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl<F: Filter> Filter for Saturating<F> {
+    fn add(&mut self, value: f32) -> f32 {
+        self.inner.add(value).clamp(self.min, self.max)
+    }
+
+    fn value(&self) -> f32 {
+        self.inner.value().clamp(self.min, self.max)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// Peak envelope detector structure
+///
+/// Jumps to any sample whose magnitude exceeds the current peak and
+/// otherwise decays geometrically towards zero.
+#[derive(Debug, Clone, Copy)]
+pub struct PeakDetector {
+    decay: f32,
+    peak: f32,
+}
+
+impl PeakDetector {
+    /// Create a new peak detector
+    ///
+    /// # Parameters
+    /// - `decay`: Factor in `[0, 1]` applied to the peak on each call that
+    ///   does not set a new peak
+    pub const fn new(decay: f32) -> Self {
+        Self { decay, peak: 0.0 }
+    }
+
+    /// Add a new value and return the current peak
+    pub fn add(&mut self, value: f32) -> f32 {
+        let magnitude = value.abs();
+        if magnitude >= self.peak {
+            self.peak = magnitude;
+        } else {
+            self.peak *= self.decay;
+        }
+
+        self.peak
+    }
+
+    /// Get the current peak without adding a new value
+    pub fn peak(&self) -> f32 {
+        self.peak
+    }
+
+    /// Reset the peak to zero
+    pub fn reset(&mut self) {
+        self.peak = 0.0;
+    }
+}
+
+impl Filter for PeakDetector {
+    fn add(&mut self, value: f32) -> f32 {
+        PeakDetector::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        self.peak
+    }
+
+    fn reset(&mut self) {
+        PeakDetector::reset(self)
+    }
+}
+
+/// Zero-crossing detector structure
+///
+/// Samples within `deadband` of zero are ignored, so noise around the
+/// axis cannot produce spurious crossings.
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroCrossingDetector {
+    deadband: f32,
+    positive: Option<bool>,
+}
+
+impl ZeroCrossingDetector {
+    /// Create a new zero-crossing detector
+    pub const fn new(deadband: f32) -> Self {
+        Self {
+            deadband,
+            positive: None,
+        }
+    }
+
+    /// Add a new value and return whether its sign differs from the last
+    /// sample outside the deadband
+    pub fn add(&mut self, value: f32) -> bool {
+        // NaN is ignored like in-band noise
+        if value.abs() <= self.deadband || value.is_nan() {
+            return false;
+        }
+
+        let positive = value > 0.0;
+        let crossed = self.positive.is_some_and(|prev| prev != positive);
+        self.positive = Some(positive);
+        crossed
+    }
+
+    /// Forget the previous sign
+    pub fn reset(&mut self) {
+        self.positive = None;
+    }
+}
+
+/// Threshold-crossing event counter structure
+///
+/// Counts transitions from at-or-below the threshold to above it.
+#[derive(Debug, Clone, Copy)]
+pub struct ThresholdCounter {
+    threshold: f32,
+    above: bool,
+    count: u32,
+}
+
+impl ThresholdCounter {
+    /// Create a new counter that starts below `threshold`
+    pub const fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            above: false,
+            count: 0,
+        }
+    }
+
+    /// Add a new value and return the number of upward crossings so far
+    pub fn add(&mut self, value: f32) -> u32 {
+        let above = value > self.threshold;
+        if above && !self.above {
+            self.count = self.count.wrapping_add(1);
+        }
+        self.above = above;
+
+        self.count
+    }
+
+    /// Get the number of upward crossings so far
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Check if the last value was above the threshold
+    pub fn is_above(&self) -> bool {
+        self.above
+    }
+
+    /// Reset the count and the above/below state
+    pub fn reset(&mut self) {
+        self.above = false;
+        self.count = 0;
+    }
+}
+
+/// Sliding trimmed-mean filter structure
+///
+/// Averages the window after discarding the `trim` smallest and `trim`
+/// largest samples. While the window holds too few samples for that, the
+/// trim is reduced so at least one sample remains.
 #[derive(Debug, Clone, Copy)]
-pub struct MovingAverage<const N: usize> {
+pub struct TrimmedMeanFilter<const N: usize> {
     buffer: [f32; N],
     index: usize,
     count: usize,
-    sum: f32,
+    trim: usize,
 }
 
-impl<const N: usize> MovingAverage<N> {
-    /// Create a new moving average filter
-    pub const fn new() -> Self {
+impl<const N: usize> TrimmedMeanFilter<N> {
+    /// Create a new trimmed-mean filter discarding `trim` samples at each end
+    pub const fn new(trim: usize) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         Self {
             buffer: [0.0; N],
             index: 0,
             count: 0,
-            sum: 0.0,
+            trim,
         }
     }
 
-    /// Add a new value and return the current average
+    /// Add a new value and return the trimmed mean of the buffered values
     pub fn add(&mut self, value: f32) -> f32 {
         if self.count < N {
-            // Buffer is not full yet
-            self.sum += value;
             self.buffer[self.count] = value;
             self.count += 1;
         } else {
-            // Buffer is full, replace the oldest value
-            self.sum -= self.buffer[self.index];
-            self.sum += value;
             self.buffer[self.index] = value;
             self.index = (self.index + 1) % N;
         }
 
-        self.sum / (self.count as f32)
+        self.value()
     }
 
-    /// Get the current average without adding a new value
-    pub fn average(&self) -> f32 {
-        if self.count == 0 {
-            0.0
-        } else {
-            self.sum / (self.count as f32)
+    /// Get the current trimmed mean without adding a new value
+    pub fn value(&self) -> f32 {
+        let len = self.count;
+        if len == 0 {
+            return 0.0;
+        }
+
+        let trim = self.trim.min((len - 1) / 2);
+        let mut scratch = self.buffer;
+        let scratch = &mut scratch[..len];
+
+        // Selection-sort only the `trim` extremes out to each end
+        for i in 0..trim {
+            let mut smallest = i;
+            let mut largest = i;
+            for j in i + 1..len - i {
+                if scratch[j].total_cmp(&scratch[smallest]).is_lt() {
+                    smallest = j;
+                }
+                if scratch[j].total_cmp(&scratch[largest]).is_gt() {
+                    largest = j;
+                }
+            }
+            scratch.swap(i, smallest);
+            if largest == i {
+                // The largest value was just moved out of slot `i`
+                largest = smallest;
+            }
+            scratch.swap(len - 1 - i, largest);
         }
+
+        let kept = &scratch[trim..len - trim];
+        kept.iter().sum::<f32>() / kept.len() as f32
     }
 
     /// Reset the filter state
     pub fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        self.sum = 0.0;
     }
 
     /// Get the number of valid data points
@@ -80,9 +1645,17 @@ impl<const N: usize> MovingAverage<N> {
     }
 }
 
-impl<const N: usize> Default for MovingAverage<N> {
-    fn default() -> Self {
-        Self::new()
+impl<const N: usize> Filter for TrimmedMeanFilter<N> {
+    fn add(&mut self, value: f32) -> f32 {
+        TrimmedMeanFilter::add(self, value)
+    }
+
+    fn value(&self) -> f32 {
+        TrimmedMeanFilter::value(self)
+    }
+
+    fn reset(&mut self) {
+        TrimmedMeanFilter::reset(self)
     }
 }
 
@@ -119,4 +1692,723 @@ mod tests {
         assert_eq!(ma.len(), 0);
         assert_eq!(ma.average(), 0.0);
     }
+
+    #[test]
+    fn test_generic_sample_types() {
+        let mut ma: GenericMovingAverage<f64, 3> = GenericMovingAverage::new();
+        assert_eq!(ma.add(0.1), 0.1);
+        assert_eq!(ma.add(0.2), (0.1 + 0.2) / 2.0);
+        ma.add(0.3);
+        assert!((ma.add(0.4) - 0.3).abs() < 1e-12);
+
+        let mut ma: GenericMovingAverage<i32, 2> = GenericMovingAverage::new();
+        assert_eq!(ma.average(), 0);
+        assert_eq!(ma.add(10), 10);
+        assert_eq!(ma.add(-4), 3);
+        assert_eq!(ma.add(7), 1); // (-4 + 7) / 2, truncated
+        assert_eq!(ma.len(), 2);
+    }
+
+    #[test]
+    fn test_median_filter_rejects_spike() {
+        let mut mf: MedianFilter<3> = MedianFilter::new();
+        assert_eq!(mf.median(), 0.0);
+        assert_eq!(mf.add(1.0), 1.0);
+        assert_eq!(mf.add(3.0), 2.0);
+
+        let signal = [2.0, 2.0, 100.0, 2.0, 2.0, -50.0, 2.0];
+        for value in signal {
+            assert_eq!(mf.add(value), 2.0);
+        }
+        assert_eq!(mf.len(), 3);
+    }
+
+    #[test]
+    fn test_min_max_filters_match_brute_force() {
+        const W: usize = 4;
+        let signal = [
+            3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0, 9.0, 7.0, 9.0, 3.0,
+        ];
+        let mut min_filter: MinFilter<W> = MinFilter::new();
+        let mut max_filter: MaxFilter<W> = MaxFilter::new();
+
+        for (i, &value) in signal.iter().enumerate() {
+            let window = &signal[(i + 1).saturating_sub(W)..=i];
+            let min = window.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = window.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+            assert_eq!(min_filter.add(value), min);
+            assert_eq!(max_filter.add(value), max);
+        }
+
+        min_filter.reset();
+        assert_eq!(min_filter.value(), 0.0);
+        assert_eq!(min_filter.add(-1.0), -1.0);
+    }
+
+    #[test]
+    fn test_running_stats_matches_batch() {
+        let samples = [2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut stats = RunningStats::new();
+        for value in samples {
+            stats.add(value);
+        }
+
+        let n = samples.len() as f32;
+        let mean = samples.iter().sum::<f32>() / n;
+        let variance = samples.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / n;
+
+        assert_eq!(stats.count(), 8);
+        assert!((stats.mean() - mean).abs() < 1e-6);
+        assert!((stats.variance() - variance).abs() < 1e-5);
+        assert!((stats.std_dev() - 2.0).abs() < 1e-5);
+
+        stats.reset();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.variance(), 0.0);
+    }
+
+    #[test]
+    fn test_kalman_converges_on_constant() {
+        let mut kf = KalmanFilter1D::new(1e-5, 0.5, 0.0, 10.0);
+        let noise = [0.4, -0.3, 0.5, -0.6, 0.2, -0.1, 0.3, -0.4];
+
+        let mut last_covariance = kf.covariance();
+        for i in 0..200 {
+            kf.update(5.0 + noise[i % noise.len()]);
+            assert!(kf.covariance() < last_covariance);
+            last_covariance = kf.covariance();
+        }
+
+        assert!((kf.estimate() - 5.0).abs() < 0.1);
+        assert!(kf.covariance() < 0.01);
+
+        kf.reset();
+        assert_eq!(kf.estimate(), 0.0);
+        assert_eq!(kf.covariance(), 10.0);
+    }
+
+    #[test]
+    fn test_low_pass_step_response() {
+        let cutoff = 1.0;
+        let rate = 100.0;
+        let mut lpf = LowPassFilter::new(cutoff, rate);
+        assert_eq!(lpf.add(0.0), 0.0);
+
+        // One time constant RC = 1 / (2 * pi * fc), in samples
+        let tau = (rate / (2.0 * core::f32::consts::PI * cutoff) + 0.5) as usize;
+        let mut output = 0.0;
+        for _ in 0..tau {
+            output = lpf.add(1.0);
+        }
+        assert!((output - 0.632).abs() < 0.02, "output {output}");
+
+        for _ in 0..10 * tau {
+            output = lpf.add(1.0);
+        }
+        assert!((output - 1.0).abs() < 1e-3);
+        assert_eq!(lpf.value(), output);
+
+        lpf.reset();
+        assert_eq!(lpf.add(3.0), 3.0);
+    }
+
+    #[test]
+    fn test_sum() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        assert_eq!(ma.sum(), 0.0);
+
+        ma.add(1.0);
+        ma.add(2.0);
+        assert_eq!(ma.sum(), 3.0);
+
+        ma.add(3.0);
+        ma.add(4.0); // Replaces 1.0
+        assert_eq!(ma.sum(), 9.0);
+        ma.add(5.0); // Replaces 2.0
+        assert_eq!(ma.sum(), 12.0);
+        assert_eq!(ma.sum(), ma.average() * ma.len() as f32);
+    }
+
+    #[test]
+    fn test_iter_chronological() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        assert_eq!(ma.iter().next(), None);
+
+        ma.add(1.0);
+        ma.add(2.0);
+        {
+            let mut iter = ma.iter();
+            assert_eq!(iter.next(), Some(1.0));
+            assert_eq!(iter.next(), Some(2.0));
+            assert_eq!(iter.next(), None);
+        }
+
+        ma.add(3.0);
+        ma.add(4.0);
+        let mut window = [0.0; 3];
+        for (slot, value) in window.iter_mut().zip(ma.iter()) {
+            *slot = value;
+        }
+        assert_eq!(window, [2.0, 3.0, 4.0]);
+        assert_eq!(ma.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        for i in 0..3 {
+            assert!(!ma.is_full());
+            ma.add(i as f32);
+        }
+        assert!(ma.is_full());
+        ma.add(3.0);
+        assert!(ma.is_full());
+        ma.reset();
+        assert!(!ma.is_full());
+    }
+
+    #[test]
+    fn test_reset_with() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        ma.add(1.0);
+        ma.add(2.0);
+        ma.reset_with(5.0);
+
+        assert_eq!(ma.average(), 5.0);
+        assert_eq!(ma.len(), 1);
+        assert_eq!(ma.add(7.0), 6.0);
+    }
+
+    #[test]
+    fn test_add_checked_skips_invalid() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        assert_eq!(ma.add_checked(1.0), Some(1.0));
+        assert_eq!(ma.add_checked(f32::NAN), None);
+        assert_eq!(ma.add_checked(f32::INFINITY), None);
+        assert_eq!(ma.add_checked(3.0), Some(2.0));
+        assert_eq!(ma.len(), 2);
+        assert_eq!(ma.average(), 2.0);
+
+        let mut ma: GenericMovingAverage<i32, 2> = GenericMovingAverage::new();
+        assert_eq!(ma.add_checked(i32::MIN / 2), Some(i32::MIN / 2));
+    }
+
+    #[test]
+    fn test_cumulative_average() {
+        let mut ca = CumulativeAverage::new();
+        assert_eq!(ca.value(), 0.0);
+
+        let stream = [3.0f32, 7.0, 1.0, 9.0, 5.0, 11.0, -2.0];
+        let mut sum = 0.0;
+        for (i, &value) in stream.iter().enumerate() {
+            sum += value;
+            let mean = ca.add(value);
+            assert!((mean - sum / (i + 1) as f32).abs() < 1e-6);
+        }
+        assert_eq!(ca.count(), stream.len());
+
+        ca.reset();
+        assert_eq!(ca.count(), 0);
+        assert_eq!(ca.add(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_no_drift_over_long_runs() {
+        let mut ma: MovingAverage<4> = MovingAverage::new();
+        let values = [10000.1f32, 0.1, -3000.7, 0.3, 7.9];
+
+        for i in 0..1_000_003 {
+            ma.add(values[i % values.len()]);
+        }
+
+        let exact = ma.iter().sum::<f32>() / 4.0;
+        assert!((ma.average() - exact).abs() < 1e-3);
+
+        // f32 samples are summed exactly in f64, so check the recompute on
+        // f64 samples, whose accumulator does pick up rounding error
+        let mut ma: GenericMovingAverage<f64, 4> = GenericMovingAverage::new();
+        let values = [10000.1f64, 0.1, -3000.7, 0.3, 7.9];
+
+        // Stop right after a wrap, when the sum has just been recomputed
+        for i in 0..1_000_004 {
+            ma.add(values[i % values.len()]);
+        }
+        assert_eq!(ma.index, 0);
+        assert_eq!(ma.sum(), ma.iter().sum::<f64>());
+    }
+
+    #[test]
+    fn test_variance() {
+        let mut ma: MovingAverage<4> = MovingAverage::new();
+        assert_eq!(ma.variance(), 0.0);
+        ma.add(5.0);
+        assert_eq!(ma.variance(), 0.0);
+
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            ma.add(value);
+        }
+        // Window is [1, 2, 3, 4] with mean 2.5
+        assert!((ma.variance() - 1.25).abs() < 1e-6);
+
+        let mut ma: GenericMovingAverage<u32, 3> = GenericMovingAverage::new();
+        for value in [2, 4, 6] {
+            ma.add(value);
+        }
+        assert!((ma.variance() - 8.0 / 3.0).abs() < 1e-6);
+
+        let mut ma: GenericMovingAverage<i32, 2> = GenericMovingAverage::new();
+        ma.add(0);
+        ma.add(100_000);
+        assert_eq!(ma.variance(), 2.5e9);
+
+        let mut ma: GenericMovingAverage<u8, 4> = GenericMovingAverage::new();
+        for value in [0, u8::MAX, 0, u8::MAX] {
+            ma.add(value);
+        }
+        assert_eq!(ma.variance(), 127.5 * 127.5);
+    }
+
+    #[test]
+    fn test_outlier_filter_rejects_spikes() {
+        let mut of: OutlierFilter<5> = OutlierFilter::new(3.0);
+        let clean = [
+            1.0f32, 1.2, 0.9, 1.1, 1.0, 0.8, 1.2, 1.0, 0.9, 1.1, 1.0, 1.2,
+        ];
+
+        for (i, &value) in clean.iter().enumerate() {
+            if i >= 5 && i % 3 == 0 {
+                // Inject a spike and expect it to be replaced
+                let previous = of.value();
+                assert_eq!(of.add(value + 50.0), previous);
+            }
+            assert_eq!(of.add(value), value);
+        }
+
+        of.reset();
+        assert_eq!(of.value(), 0.0);
+        assert_eq!(of.add(99.0), 99.0);
+    }
+
+    #[test]
+    fn test_newest_oldest() {
+        let mut ma: MovingAverage<3> = MovingAverage::new();
+        assert_eq!(ma.newest(), None);
+        assert_eq!(ma.oldest(), None);
+
+        ma.add(1.0);
+        assert_eq!(ma.newest(), Some(1.0));
+        assert_eq!(ma.oldest(), Some(1.0));
+
+        for value in [2.0, 3.0, 4.0, 5.0] {
+            ma.add(value);
+        }
+        // Window has wrapped to [3, 4, 5]
+        assert_eq!(ma.newest(), Some(5.0));
+        assert_eq!(ma.oldest(), Some(3.0));
+    }
+
+    #[test]
+    fn test_rms_of_sine() {
+        use core::f32::consts::FRAC_1_SQRT_2;
+
+        // One period of a sine wave sampled at 8 points, amplitude 2
+        let amplitude = 2.0;
+        let period = [
+            0.0,
+            FRAC_1_SQRT_2,
+            1.0,
+            FRAC_1_SQRT_2,
+            0.0,
+            -FRAC_1_SQRT_2,
+            -1.0,
+            -FRAC_1_SQRT_2,
+        ];
+        let mut rms: RmsFilter<8> = RmsFilter::new();
+
+        let mut output = 0.0;
+        for _ in 0..10 {
+            for s in period {
+                output = rms.add(amplitude * s);
+            }
+        }
+        assert!((output - amplitude * FRAC_1_SQRT_2).abs() < 1e-5);
+
+        rms.reset();
+        assert_eq!(rms.value(), 0.0);
+        assert_eq!(rms.add(-3.0), 3.0);
+    }
+
+    #[test]
+    fn test_fir_impulse_response() {
+        let taps = [0.25, 0.5, 0.25];
+        let mut fir = FirFilter::new(taps);
+
+        // The impulse response of an FIR filter is its tap set
+        assert_eq!(fir.add(1.0), 0.25);
+        assert_eq!(fir.add(0.0), 0.5);
+        assert_eq!(fir.add(0.0), 0.25);
+        assert_eq!(fir.add(0.0), 0.0);
+
+        // Unity DC gain: a constant input passes through
+        for _ in 0..3 {
+            fir.add(2.0);
+        }
+        assert_eq!(fir.value(), 2.0);
+
+        fir.reset();
+        assert_eq!(fir.add(4.0), 1.0);
+    }
+
+    #[test]
+    fn test_debounce_bouncing_input() {
+        let mut db = Debounce::new(3);
+
+        // Contact bounce never holds long enough to register
+        for raw in [true, false, true, true, false, true, false] {
+            assert!(!db.update(raw));
+        }
+
+        // Three consecutive highs settle the output
+        assert!(!db.update(true));
+        assert!(!db.update(true));
+        assert!(db.update(true));
+
+        // A single low glitch is ignored
+        assert!(db.update(false));
+        assert!(db.update(true));
+        assert!(db.state());
+
+        db.reset();
+        assert!(!db.state());
+    }
+
+    #[test]
+    fn test_hysteresis_no_flapping() {
+        let mut hy = Hysteresis::new(0.4, 0.6);
+
+        // Noisy signal rising through the 0.5 mid-point
+        let rising = [
+            0.1, 0.3, 0.48, 0.52, 0.49, 0.55, 0.51, 0.65, 0.58, 0.45, 0.62,
+        ];
+        let mut changes = 0;
+        let mut last = hy.state();
+        for value in rising {
+            let state = hy.update(value);
+            if state != last {
+                changes += 1;
+                last = state;
+            }
+        }
+        assert_eq!(changes, 1);
+        assert!(hy.state());
+
+        // Dips above the low threshold keep the output on
+        assert!(hy.update(0.41));
+        assert!(!hy.update(0.39));
+
+        hy.update(0.9);
+        hy.reset();
+        assert!(!hy.state());
+    }
+
+    #[test]
+    fn test_slew_rate_ramp() {
+        let mut slew = SlewRateLimiter::new(0.5);
+
+        // A step to 2.0 ramps up linearly and then holds
+        for expected in [0.5, 1.0, 1.5, 2.0, 2.0] {
+            assert_eq!(slew.add(2.0), expected);
+        }
+
+        // Small changes pass through unchanged
+        assert_eq!(slew.add(1.75), 1.75);
+        // And the ramp works downwards too
+        assert_eq!(slew.add(-10.0), 1.25);
+
+        slew.reset_to(-3.0);
+        assert_eq!(slew.value(), -3.0);
+        assert_eq!(slew.add(0.0), -2.5);
+
+        // A negative limit is taken by its magnitude instead of panicking
+        let mut slew = SlewRateLimiter::new(-0.5);
+        assert_eq!(slew.add(2.0), 0.5);
+        assert_eq!(slew.add(-2.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid slew rate")]
+    fn test_slew_rate_rejects_nan() {
+        SlewRateLimiter::new(f32::NAN);
+    }
+
+    #[test]
+    fn test_integrator_constant_input() {
+        let mut integrator = Integrator::new();
+        for step in 1..=10 {
+            let integral = integrator.add(2.0, 0.5);
+            assert_eq!(integral, step as f32);
+        }
+        assert_eq!(integrator.value(), 10.0);
+
+        integrator.reset();
+        assert_eq!(integrator.value(), 0.0);
+
+        // Trapezoidal rule integrates a ramp exactly
+        let mut integrator = Integrator::trapezoidal();
+        for t in 0..=4 {
+            integrator.add(t as f32, 1.0);
+        }
+        assert_eq!(integrator.value(), 8.0);
+    }
+
+    #[test]
+    fn test_alpha_beta_tracks_slope() {
+        let mut ab = AlphaBetaFilter::new(0.5, 0.1);
+        let noise = [0.3, -0.2, 0.1, -0.3, 0.2, -0.1];
+        let slope = 2.0;
+        let dt = 0.1;
+
+        for i in 0..300 {
+            let t = i as f32 * dt;
+            ab.update(1.0 + slope * t + noise[i % noise.len()], dt);
+        }
+
+        assert!(
+            (ab.velocity() - slope).abs() < 0.2,
+            "velocity {}",
+            ab.velocity()
+        );
+        let t_end = 299.0 * dt;
+        assert!((ab.position() - (1.0 + slope * t_end)).abs() < 0.5);
+
+        ab.reset();
+        assert_eq!(ab.update(7.0, dt), (7.0, 0.0));
+    }
+
+    #[test]
+    fn test_complementary_filter() {
+        let mut cf = ComplementaryFilter::new(0.98);
+
+        // Typical IMU use: the fast input integrates a gyro rate from the
+        // previous fused angle, the slow input is the accelerometer angle
+        let dt = 0.01;
+        let gyro_bias = 0.5;
+        let accel_angle = 10.0;
+        for _ in 0..2000 {
+            let fast = cf.value() + gyro_bias * dt;
+            cf.update(fast, accel_angle);
+        }
+        // Steady state follows the slow signal despite the gyro bias
+        assert!((cf.value() - accel_angle).abs() < 0.5);
+
+        // A sudden rotation shows up almost entirely through the fast input
+        let before = cf.value();
+        let fused = cf.update(before + 5.0, accel_angle);
+        assert!((fused - before - 5.0 * 0.98).abs() < 0.05);
+
+        cf.reset();
+        assert_eq!(cf.value(), 0.0);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let mut avg = Saturating::new(MovingAverage::<2>::new(), -1.0, 1.0);
+        assert_eq!(Filter::add(&mut avg, 0.5), 0.5);
+        assert_eq!(Filter::add(&mut avg, 10.0), 1.0); // Raw average 5.25
+        assert_eq!(avg.inner().average(), 5.25);
+        assert_eq!(Filter::add(&mut avg, -10.0), 0.0);
+        assert_eq!(Filter::add(&mut avg, -10.0), -1.0);
+
+        let mut lpf = LowPassFilter::new(1.0, 100.0).with_limits(0.0, 3.3);
+        assert_eq!(Filter::add(&mut lpf, 12.0), 3.3);
+        assert_eq!(Filter::value(&lpf), 3.3);
+        Filter::reset(&mut lpf);
+        assert_eq!(lpf.into_inner().value(), 0.0);
+    }
+
+    #[test]
+    fn test_peak_detector() {
+        let mut pd = PeakDetector::new(0.5);
+        assert_eq!(pd.add(8.0), 8.0);
+
+        // Geometric decay after the impulse
+        assert_eq!(pd.add(0.0), 4.0);
+        assert_eq!(pd.add(0.0), 2.0);
+        assert_eq!(pd.add(0.0), 1.0);
+
+        // Negative swings count by magnitude
+        assert_eq!(pd.add(-3.0), 3.0);
+        assert_eq!(pd.peak(), 3.0);
+
+        pd.reset();
+        assert_eq!(pd.peak(), 0.0);
+    }
+
+    #[test]
+    fn test_zero_crossing_detector() {
+        // One cycle of a sampled sine with a little jitter around zero
+        let cycle = [0.02, 0.7, 1.0, 0.7, -0.03, -0.7, -1.0, -0.7];
+        let mut zc = ZeroCrossingDetector::new(0.1);
+
+        let mut crossings = 0;
+        for _ in 0..4 {
+            for &v in &cycle {
+                if zc.add(v) {
+                    crossings += 1;
+                }
+            }
+        }
+        // Two crossings per cycle, except the very first upward one which
+        // has no previous sign to compare against
+        assert_eq!(crossings, 7);
+
+        // Noise inside the deadband never triggers
+        zc.reset();
+        for v in [0.05, -0.05, 0.08, -0.09] {
+            assert!(!zc.add(v));
+        }
+        assert!(!zc.add(f32::NAN));
+    }
+
+    #[test]
+    fn test_threshold_counter() {
+        let mut tc = ThresholdCounter::new(1.0);
+        let signal = [0.0, 2.0, 3.0, 0.5, 1.0, 1.5, -1.0, 2.0, 2.0];
+        let mut last = 0;
+        for v in signal {
+            last = tc.add(v);
+        }
+        // Downward crossings and touching the threshold do not count
+        assert_eq!(last, 3);
+        assert_eq!(tc.count(), 3);
+        assert!(tc.is_above());
+
+        tc.reset();
+        assert_eq!(tc.count(), 0);
+        assert!(!tc.is_above());
+    }
+
+    #[test]
+    fn test_trimmed_mean_filter() {
+        let mut tm = TrimmedMeanFilter::<5>::new(1);
+        assert_eq!(tm.add(100.0), 100.0); // Too few samples to trim
+        assert_eq!(tm.add(2.0), 51.0);
+        assert_eq!(tm.add(3.0), 3.0); // 100.0 and 2.0 discarded
+        tm.add(-50.0);
+        assert_eq!(tm.add(4.0), 3.0); // 100.0 and -50.0 discarded
+
+        // Largest value sitting in the first slot of the scratch window
+        tm.reset();
+        for v in [9.0, 1.0, 5.0, 6.0, 4.0] {
+            tm.add(v);
+        }
+        assert_eq!(tm.value(), 5.0);
+        assert_eq!(tm.len(), 5);
+
+        tm.reset();
+        assert!(tm.is_empty());
+        assert_eq!(tm.value(), 0.0);
+    }
+
+    /// Run `input` through two filters in series, returning the last output
+    fn process<A: Filter, B: Filter>(first: &mut A, second: &mut B, input: &[f32]) -> f32 {
+        input.iter().fold(0.0, |_, &v| second.add(first.add(v)))
+    }
+
+    #[test]
+    fn test_filter_pipeline() {
+        // Median removes the spike, moving average smooths the rest
+        let mut median = MedianFilter::<3>::new();
+        let mut average = MovingAverage::<2>::new();
+        let out = process(&mut median, &mut average, &[1.0, 1.0, 50.0, 1.0, 3.0]);
+        assert_eq!(out, 2.0);
+        assert_eq!(Filter::value(&average), 2.0);
+
+        // Any filter can sit on either side, including wrapped ones
+        let mut rms = RmsFilter::<2>::new();
+        let mut limited = SlewRateLimiter::new(1.0).with_limits(0.0, 2.5);
+        let out = process(&mut rms, &mut limited, &[3.0, 4.0, 4.0, 4.0]);
+        assert_eq!(out, 2.5);
+
+        Filter::reset(&mut median);
+        Filter::reset(&mut limited);
+        assert_eq!(Filter::value(&median), 0.0);
+        assert_eq!(Filter::value(&limited), 0.0);
+    }
+
+    #[test]
+    fn test_integer_accumulator_does_not_overflow() {
+        let mut ma: GenericMovingAverage<u8, 2> = GenericMovingAverage::new();
+        assert_eq!(ma.add(200), 200);
+        assert_eq!(ma.add(200), 200);
+        assert_eq!(ma.sum(), u8::MAX); // Saturated
+        assert_eq!(ma.wide_sum(), 400);
+
+        let mut ma: GenericMovingAverage<u8, 300> = GenericMovingAverage::new();
+        for _ in 0..400 {
+            assert_eq!(ma.add(u8::MAX), u8::MAX);
+        }
+        assert_eq!(ma.wide_sum(), 300 * u8::MAX as u64);
+
+        let mut ma: GenericMovingAverage<i8, 300> = GenericMovingAverage::new();
+        for _ in 0..300 {
+            assert_eq!(ma.add(i8::MIN), i8::MIN);
+        }
+        for _ in 0..300 {
+            ma.add(i8::MAX);
+        }
+        assert_eq!(ma.average(), i8::MAX);
+        assert_eq!(ma.wide_sum(), 300 * i8::MAX as i64);
+        ma.reset();
+        ma.add(i8::MIN);
+        ma.add(i8::MIN);
+        assert_eq!(ma.sum(), i8::MIN);
+        assert_eq!(ma.wide_sum(), -256);
+    }
+
+    #[test]
+    fn test_min_max_filter_counter_wraps() {
+        let mut min: MinFilter<3> = MinFilter::new();
+        let mut max: MaxFilter<3> = MaxFilter::new();
+        min.window.samples = usize::MAX - 2;
+        max.window.samples = usize::MAX - 2;
+
+        let input = [1.0f32, 5.0, 6.0, 7.0, 8.0, 2.0, 0.5];
+        let expected_min = [1.0f32, 1.0, 1.0, 5.0, 6.0, 2.0, 0.5];
+        let expected_max = [1.0f32, 5.0, 6.0, 7.0, 8.0, 8.0, 8.0];
+        for (i, &value) in input.iter().enumerate() {
+            assert_eq!(min.add(value), expected_min[i]);
+            assert_eq!(max.add(value), expected_max[i]);
+        }
+    }
+
+    #[test]
+    fn test_outlier_filter_rejects_non_finite() {
+        let mut of: OutlierFilter<3> = OutlierFilter::new(3.0);
+        assert_eq!(of.add(f32::NAN), 0.0);
+        assert_eq!(of.add(1.0), 1.0);
+        assert_eq!(of.add(f32::INFINITY), 1.0);
+        assert_eq!(of.add(f32::NEG_INFINITY), 1.0);
+        assert_eq!(of.add(2.0), 2.0);
+        assert_eq!(of.add(f32::NAN), 2.0);
+        assert_eq!(of.add(3.0), 3.0);
+
+        // The window only ever held finite samples, so filtering carries on
+        assert_eq!(of.add(2.5), 2.5);
+        assert_eq!(of.add(100.0), 2.5);
+    }
+
+    #[test]
+    fn test_outlier_filter_zero_mad() {
+        let mut of: OutlierFilter<3> = OutlierFilter::new(3.0);
+        for _ in 0..3 {
+            of.add(5.0);
+        }
+        // Flat window: even a tiny step is an outlier until it fills most
+        // of the window
+        assert_eq!(of.add(5.001), 5.0);
+        assert_eq!(of.add(5.001), 5.0);
+        assert_eq!(of.add(5.001), 5.001);
+    }
 }
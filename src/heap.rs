@@ -0,0 +1,396 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use core::cmp::Ordering;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+
+/// Restore the max-heap property upwards from `pos`
+///
+/// `is_less(a, b)` defines the ordering; the greatest element ends up at
+/// index 0.
+pub(crate) fn sift_up<T, F: FnMut(&T, &T) -> bool>(
+    data: &mut [T],
+    mut pos: usize,
+    is_less: &mut F,
+) {
+    while pos > 0 {
+        let parent = (pos - 1) / 2;
+        if !is_less(&data[parent], &data[pos]) {
+            break;
+        }
+        data.swap(parent, pos);
+        pos = parent;
+    }
+}
+
+/// Restore the max-heap property downwards from `pos`
+pub(crate) fn sift_down<T, F: FnMut(&T, &T) -> bool>(
+    data: &mut [T],
+    mut pos: usize,
+    is_less: &mut F,
+) {
+    let len = data.len();
+    loop {
+        let left = 2 * pos + 1;
+        if left >= len {
+            break;
+        }
+
+        // Pick the greater child
+        let right = left + 1;
+        let child = if right < len && is_less(&data[left], &data[right]) {
+            right
+        } else {
+            left
+        };
+
+        if !is_less(&data[pos], &data[child]) {
+            break;
+        }
+        data.swap(pos, child);
+        pos = child;
+    }
+}
+
+/// Array-based binary max-heap
+pub struct ArrayBinaryHeap<T: Ord, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T: Ord, const N: usize> ArrayBinaryHeap<T, N> {
+    /// Create a new empty heap
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this heap type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` plus one `usize` for the length.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// View the initialized prefix in heap order
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: The first `len` elements are initialized
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Add an element to the heap
+    /// Returns Err(element) if the heap is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+
+        self.data[self.len].write(item);
+        self.len += 1;
+        let last = self.len - 1;
+        sift_up(self.as_mut_slice(), last, &mut T::lt);
+        Ok(())
+    }
+
+    /// Remove and return the greatest element
+    /// Returns None if the heap is empty
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = self.len - 1;
+        self.as_mut_slice().swap(0, last);
+        self.len -= 1;
+        // Safety: The slot at `len` was initialized and is no longer tracked
+        let item = unsafe { self.data[self.len].assume_init_read() };
+        sift_down(self.as_mut_slice(), 0, &mut T::lt);
+        Some(item)
+    }
+
+    /// Peek at the greatest element
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // Safety: The root is initialized when the heap is not empty
+        Some(unsafe { self.data[0].assume_init_ref() })
+    }
+
+    /// Check if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the heap is full
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the current number of elements in the heap
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of elements the heap can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Clear the heap
+    pub fn clear(&mut self) {
+        let len = self.len;
+        // Reset first so a panicking destructor cannot cause a double drop
+        self.len = 0;
+        for slot in &mut self.data[..len] {
+            unsafe {
+                ptr::drop_in_place(slot.as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<T: Ord, const N: usize> Default for ArrayBinaryHeap<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drop handler to process initialized elements
+impl<T: Ord, const N: usize> Drop for ArrayBinaryHeap<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Heap entry ordered by priority, then by insertion order
+struct Entry<T, P> {
+    priority: P,
+    seq: u64,
+    item: T,
+}
+
+impl<T, P: Ord> Ord for Entry<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Earlier insertions rank higher among equal priorities
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<T, P: Ord> PartialOrd for Entry<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, P: Ord> PartialEq for Entry<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, P: Ord> Eq for Entry<T, P> {}
+
+/// Array-based priority queue keeping the priority apart from the payload
+///
+/// Items come out highest priority first. Items with equal priority come
+/// out in the order they were pushed.
+pub struct PriorityQueue<T, P: Ord, const N: usize> {
+    heap: ArrayBinaryHeap<Entry<T, P>, N>,
+    next_seq: u64,
+}
+
+impl<T, P: Ord, const N: usize> PriorityQueue<T, P, N> {
+    /// Create a new empty priority queue
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            heap: ArrayBinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// Memory footprint of this queue type in bytes
+    ///
+    /// Roughly `N` entries of payload, priority and sequence number, plus the
+    /// heap length and the next sequence number.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Add an item with the given priority
+    /// Returns Err(item) if the queue is full
+    pub fn push(&mut self, priority: P, item: T) -> Result<(), T> {
+        let entry = Entry {
+            priority,
+            seq: self.next_seq,
+            item,
+        };
+        self.heap.push(entry).map_err(|entry| entry.item)?;
+        self.next_seq += 1;
+        Ok(())
+    }
+
+    /// Remove and return the highest-priority item
+    /// Returns None if the queue is empty
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|entry| entry.item)
+    }
+
+    /// Peek at the highest-priority item
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek().map(|entry| &entry.item)
+    }
+
+    /// Peek at the highest priority currently queued
+    pub fn peek_priority(&self) -> Option<&P> {
+        self.heap.peek().map(|entry| &entry.priority)
+    }
+
+    /// Check if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Check if the queue is full
+    pub fn is_full(&self) -> bool {
+        self.heap.is_full()
+    }
+
+    /// Return the current number of items in the queue
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Clear the queue
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<T, P: Ord, const N: usize> Default for PriorityQueue<T, P, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> FixedCapacity for ArrayBinaryHeap<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_pop_order() {
+        let mut heap: ArrayBinaryHeap<i32, 8> = ArrayBinaryHeap::new();
+        for v in [5, 1, 8, 3, 9, 2, 7, 3] {
+            assert_eq!(heap.push(v), Ok(()));
+        }
+        assert_eq!(heap.peek(), Some(&9));
+
+        let mut out = [0; 8];
+        for slot in &mut out {
+            *slot = heap.pop().unwrap();
+        }
+        assert_eq!(out, [9, 8, 7, 5, 3, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_full_and_empty() {
+        let mut heap: ArrayBinaryHeap<i32, 2> = ArrayBinaryHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.peek(), None);
+
+        assert_eq!(heap.push(1), Ok(()));
+        assert_eq!(heap.push(2), Ok(()));
+        assert!(heap.is_full());
+        assert_eq!(heap.push(3), Err(3)); // Heap is full
+        assert_eq!(heap.len(), 2);
+
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.push(3), Ok(())); // Now there is space
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.pop(), Some(1));
+        assert!(heap.is_empty());
+    }
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted<'a>(i32, &'a Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+        {
+            let mut heap: ArrayBinaryHeap<Counted, 4> = ArrayBinaryHeap::new();
+            for v in [2, 4, 1] {
+                assert!(heap.push(Counted(v, &drops)).is_ok());
+            }
+            assert_eq!(heap.pop().map(|c| c.0), Some(4));
+            assert_eq!(drops.get(), 1);
+        }
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn test_priority_queue() {
+        let mut queue: PriorityQueue<&str, u8, 5> = PriorityQueue::new();
+        assert_eq!(queue.pop(), None);
+
+        assert_eq!(queue.push(1, "low"), Ok(()));
+        assert_eq!(queue.push(5, "urgent"), Ok(()));
+        assert_eq!(queue.push(3, "first"), Ok(()));
+        assert_eq!(queue.push(3, "second"), Ok(()));
+        assert_eq!(queue.push(3, "third"), Ok(()));
+        assert_eq!(queue.push(9, "overflow"), Err("overflow")); // Queue is full
+
+        assert_eq!(queue.peek(), Some(&"urgent"));
+        assert_eq!(queue.peek_priority(), Some(&5));
+
+        // Highest priority first, ties in insertion order
+        assert_eq!(queue.pop(), Some("urgent"));
+        assert_eq!(queue.pop(), Some("first"));
+        assert_eq!(queue.pop(), Some("second"));
+        assert_eq!(queue.pop(), Some("third"));
+        assert_eq!(queue.pop(), Some("low"));
+        assert!(queue.is_empty());
+    }
+}
@@ -22,3 +22,26 @@ pub mod queue;
 pub mod stack;
 pub mod tree;
 pub mod filter;
+pub mod math;
+pub mod error;
+pub mod heap;
+pub mod vec;
+pub mod bitset;
+pub mod deque;
+pub mod cache;
+pub mod map;
+pub mod set;
+pub mod pool;
+pub mod buffer;
+pub mod sort;
+pub mod capacity;
+pub mod checksum;
+pub mod bytes;
+pub mod slice_util;
+pub mod string;
+
+#[cfg(test)]
+pub(crate) mod test_util;
+
+pub use capacity::FixedCapacity;
+pub use error::ArrayError;
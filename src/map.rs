@@ -0,0 +1,259 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use crate::error::ArrayError;
+use core::mem;
+
+/// Fixed-capacity hash map using open addressing with linear probing
+///
+/// `no_std` has no default hasher, so the caller supplies the hash
+/// function. Every slot can be filled, but probe sequences grow quickly
+/// once the load factor (`len / N`) passes about 0.7, so size `N` with
+/// some headroom. Inserting a new key into a full map fails with
+/// [`ArrayError::Full`].
+pub struct ArrayHashMap<K: PartialEq + Copy, V, const N: usize> {
+    slots: [Option<(K, V)>; N],
+    len: usize,
+    hasher: fn(&K) -> u64,
+}
+
+impl<K: PartialEq + Copy, V, const N: usize> ArrayHashMap<K, V, N> {
+    /// Create a new empty map using `hasher` to place keys
+    pub const fn new(hasher: fn(&K) -> u64) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            slots: [const { None }; N],
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Memory footprint of this map type in bytes
+    ///
+    /// Roughly `N * size_of::<Option<(K, V)>>()` plus the length and the hash
+    /// function pointer.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Preferred slot of `key`
+    fn home(&self, key: &K) -> usize {
+        ((self.hasher)(key) % N as u64) as usize
+    }
+
+    /// Probe for `key`, returning its slot or the first empty slot on its
+    /// probe sequence
+    fn probe(&self, key: &K) -> Result<usize, Option<usize>> {
+        let start = self.home(key);
+        for step in 0..N {
+            let i = (start + step) % N;
+            match &self.slots[i] {
+                Some((k, _)) if k == key => return Ok(i),
+                Some(_) => {}
+                None => return Err(Some(i)),
+            }
+        }
+        Err(None)
+    }
+
+    /// Insert a key-value pair
+    ///
+    /// # Return value
+    /// - `Ok(Some(V))`: The key was present, holds the replaced value
+    /// - `Ok(None)`: The key was newly inserted
+    /// - `Err(ArrayError::Full)`: The key is new and every slot is taken
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, ArrayError> {
+        match self.probe(&key) {
+            Ok(i) => {
+                let (_, slot_value) = self.slots[i].as_mut().unwrap();
+                Ok(Some(mem::replace(slot_value, value)))
+            }
+            Err(Some(i)) => {
+                self.slots[i] = Some((key, value));
+                self.len += 1;
+                Ok(None)
+            }
+            Err(None) => Err(ArrayError::Full),
+        }
+    }
+
+    /// Get the value stored for `key`
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let i = self.probe(key).ok()?;
+        self.slots[i].as_ref().map(|(_, v)| v)
+    }
+
+    /// Get the value stored for `key` as a mutable reference
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let i = self.probe(key).ok()?;
+        self.slots[i].as_mut().map(|(_, v)| v)
+    }
+
+    /// Check if `key` is present
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.probe(key).is_ok()
+    }
+
+    /// Remove `key` and return its value
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let mut hole = self.probe(key).ok()?;
+        let (_, value) = self.slots[hole].take()?;
+        self.len -= 1;
+
+        // Shift later entries of the cluster back so no probe sequence is
+        // cut short by the new gap
+        let mut i = hole;
+        loop {
+            i = (i + 1) % N;
+            let home = match &self.slots[i] {
+                Some((k, _)) => self.home(k),
+                None => break,
+            };
+            // Entries whose home lies cyclically in (hole, i] stay put
+            let stays = if hole <= i {
+                hole < home && home <= i
+            } else {
+                hole < home || home <= i
+            };
+            if !stays {
+                self.slots[hole] = self.slots[i].take();
+                hole = i;
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Check if the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if every slot is taken
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the current number of entries
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of entries the map can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Iterate over the entries in slot order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.as_ref().map(|(k, v)| (k, v)))
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = None;
+        }
+        self.len = 0;
+    }
+}
+
+impl<K: PartialEq + Copy, V, const N: usize> FixedCapacity for ArrayHashMap<K, V, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(key: &u32) -> u64 {
+        *key as u64
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map: ArrayHashMap<u32, &str, 8> = ArrayHashMap::new(identity);
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(1, "one"), Ok(None));
+        assert_eq!(map.insert(2, "two"), Ok(None));
+        assert_eq!(map.insert(1, "uno"), Ok(Some("one")));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert_eq!(map.get(&3), None);
+        if let Some(v) = map.get_mut(&2) {
+            *v = "dos";
+        }
+        assert_eq!(map.get(&2), Some(&"dos"));
+
+        assert_eq!(map.remove(&1), Some("uno"));
+        assert_eq!(map.remove(&1), None);
+        assert!(!map.contains_key(&1));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_probe_wraparound() {
+        let mut map: ArrayHashMap<u32, u32, 4> = ArrayHashMap::new(identity);
+
+        // 3, 7 and 11 all hash to the last slot and wrap to the front
+        map.insert(3, 30).unwrap();
+        map.insert(7, 70).unwrap();
+        map.insert(11, 110).unwrap();
+        assert_eq!(map.get(&7), Some(&70));
+        assert_eq!(map.get(&11), Some(&110));
+
+        // Removing the head of the cluster must keep the rest reachable
+        assert_eq!(map.remove(&3), Some(30));
+        assert_eq!(map.get(&7), Some(&70));
+        assert_eq!(map.get(&11), Some(&110));
+
+        // An entry in its home slot is not shifted over a gap behind it
+        map.insert(1, 10).unwrap();
+        assert_eq!(map.remove(&7), Some(70));
+        assert_eq!(map.get(&1), Some(&10));
+        assert_eq!(map.get(&11), Some(&110));
+        assert_eq!(map.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_full_map() {
+        let mut map: ArrayHashMap<u32, u32, 2> = ArrayHashMap::new(identity);
+        map.insert(0, 0).unwrap();
+        map.insert(2, 2).unwrap();
+        assert!(map.is_full());
+        assert_eq!(map.insert(4, 4), Err(ArrayError::Full));
+        assert_eq!(map.get(&4), None);
+
+        // Updating an existing key still works when full
+        assert_eq!(map.insert(2, 20), Ok(Some(2)));
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(4, 4), Ok(None));
+    }
+}
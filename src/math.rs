@@ -0,0 +1,108 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+/// Compute the dot product of two arrays
+///
+/// # Parameters
+/// - `a`: The first array slice
+/// - `b`: The second array slice
+///
+/// # Return value
+/// - `Some(f64)`: The sum of the elementwise products
+/// - `None`: The slices differ in length
+pub fn dot_product(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    Some(a.iter().zip(b).map(|(x, y)| x * y).sum())
+}
+
+/// Sum an `i32` array, clamping to `i32::MAX`/`i32::MIN` on overflow
+///
+/// Saturation is applied per addition, so the result depends on the order
+/// of the elements once a bound has been reached.
+pub fn saturating_sum_i32(arr: &[i32]) -> i32 {
+    arr.iter().fold(0i32, |acc, &x| acc.saturating_add(x))
+}
+
+/// Sum an `i32` array, detecting overflow
+///
+/// # Return value
+/// - `Some(i32)`: The exact sum
+/// - `None`: An intermediate sum overflowed
+pub fn checked_sum_i32(arr: &[i32]) -> Option<i32> {
+    arr.iter().try_fold(0i32, |acc, &x| acc.checked_add(x))
+}
+
+/// Square root for `no_std` targets, where `f32::sqrt` is unavailable
+///
+/// Refines a bit-level initial estimate with Newton iterations, which is
+/// accurate to within an ulp or two across the normal range.
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    if x < 0.0 {
+        return f32::NAN;
+    }
+    if x == 0.0 || !x.is_finite() {
+        return x;
+    }
+
+    let mut y = f32::from_bits((x.to_bits() >> 1) + 0x1fbd_1df5);
+    for _ in 0..4 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_product() {
+        assert_eq!(dot_product(&[1.0, 0.0], &[0.0, 1.0]), Some(0.0));
+        assert_eq!(dot_product(&[1.0, 2.0, 3.0], &[4.0, -5.0, 6.0]), Some(12.0));
+        assert_eq!(dot_product(&[], &[]), Some(0.0));
+        assert_eq!(dot_product(&[1.0, 2.0], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_integer_sums() {
+        assert_eq!(checked_sum_i32(&[1, 2, 3]), Some(6));
+        assert_eq!(saturating_sum_i32(&[1, 2, 3]), 6);
+
+        let high = [i32::MAX, 1, 2];
+        assert_eq!(checked_sum_i32(&high), None);
+        assert_eq!(saturating_sum_i32(&high), i32::MAX);
+
+        let low = [i32::MIN, -1];
+        assert_eq!(checked_sum_i32(&low), None);
+        assert_eq!(saturating_sum_i32(&low), i32::MIN);
+    }
+
+    #[test]
+    fn test_sqrt_f32() {
+        for x in [0.25f32, 1.0, 2.0, 9.0, 1e-6, 12345.678, 3.0e30] {
+            let root = sqrt_f32(x);
+            assert!((root * root - x).abs() <= x * 1e-6, "sqrt({x}) = {root}");
+        }
+        assert_eq!(sqrt_f32(0.0), 0.0);
+        assert_eq!(sqrt_f32(16.0), 4.0);
+        assert!(sqrt_f32(-1.0).is_nan());
+        assert_eq!(sqrt_f32(f32::INFINITY), f32::INFINITY);
+    }
+}
@@ -0,0 +1,175 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+
+/// Marker for the end of the free list
+const NIL: usize = usize::MAX;
+
+/// Fixed-capacity object pool handing out slot indices
+///
+/// Freed slots are kept on an intrusive free list and reused, most recently
+/// freed first, before any never-used slot.
+pub struct ObjectPool<T, const N: usize> {
+    slots: [Option<T>; N],
+    next_free: [usize; N], // Free-list links, valid for freed slots only
+    free_head: usize,      // Most recently freed slot
+    untouched: usize,      // Slots from here on have never been allocated
+    len: usize,
+}
+
+impl<T, const N: usize> ObjectPool<T, N> {
+    /// Create a new empty pool
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            slots: [const { None }; N],
+            next_free: [NIL; N],
+            free_head: NIL,
+            untouched: 0,
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this pool type in bytes
+    ///
+    /// Each of the `N` slots costs `size_of::<Option<T>>()` plus a `usize`
+    /// free-list link.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Store `value` in a free slot and return its index
+    /// Returns None if every slot is in use
+    pub fn allocate(&mut self, value: T) -> Option<usize> {
+        let index = if self.free_head != NIL {
+            let index = self.free_head;
+            self.free_head = self.next_free[index];
+            index
+        } else if self.untouched < N {
+            self.untouched += 1;
+            self.untouched - 1
+        } else {
+            return None;
+        };
+
+        self.slots[index] = Some(value);
+        self.len += 1;
+        Some(index)
+    }
+
+    /// Release the slot at `index` and return its value
+    /// Returns None if the index is out of range or the slot is not in use
+    pub fn free(&mut self, index: usize) -> Option<T> {
+        let value = self.slots.get_mut(index)?.take()?;
+        self.next_free[index] = self.free_head;
+        self.free_head = index;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Get the value in the slot at `index`
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index)?.as_ref()
+    }
+
+    /// Get the value in the slot at `index` as a mutable reference
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots.get_mut(index)?.as_mut()
+    }
+
+    /// Check if no slot is in use
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if every slot is in use
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the number of slots in use
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the total number of slots
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> Default for ObjectPool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for ObjectPool<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_to_capacity() {
+        let mut pool: ObjectPool<&str, 3> = ObjectPool::new();
+        assert!(pool.is_empty());
+
+        assert_eq!(pool.allocate("a"), Some(0));
+        assert_eq!(pool.allocate("b"), Some(1));
+        assert_eq!(pool.allocate("c"), Some(2));
+        assert!(pool.is_full());
+        assert_eq!(pool.allocate("d"), None); // Pool is full
+
+        assert_eq!(pool.get(1), Some(&"b"));
+        if let Some(v) = pool.get_mut(2) {
+            *v = "C";
+        }
+        assert_eq!(pool.get(2), Some(&"C"));
+        assert_eq!(pool.get(3), None);
+    }
+
+    #[test]
+    fn test_reuse_freed_slots() {
+        let mut pool: ObjectPool<i32, 4> = ObjectPool::new();
+        for v in 0..4 {
+            pool.allocate(v * 10).unwrap();
+        }
+
+        assert_eq!(pool.free(1), Some(10));
+        assert_eq!(pool.free(3), Some(30));
+        assert_eq!(pool.free(3), None); // Already freed
+        assert_eq!(pool.free(9), None); // Out of range
+        assert_eq!(pool.get(1), None);
+        assert_eq!(pool.len(), 2);
+
+        // Most recently freed slot comes back first
+        assert_eq!(pool.allocate(300), Some(3));
+        assert_eq!(pool.allocate(100), Some(1));
+        assert_eq!(pool.allocate(5), None);
+        assert_eq!(pool.get(3), Some(&300));
+    }
+}
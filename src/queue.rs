@@ -15,9 +15,23 @@
 // with this program; if not, write to the Free Software Foundation, Inc.,
 // 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
 
+use crate::bytes::NeBytes;
+use crate::capacity::FixedCapacity;
+use crate::error::ArrayError;
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
 
 /// Array-based circular queue implementation (optimized for Copy types)
+///
+/// `N` must be greater than zero. Both queue types check this when they are
+/// constructed, so a zero-capacity queue fails to compile instead of
+/// dividing by zero at runtime:
+///
+/// ```compile_fail
+/// use arraypp::queue::ArrayQueue;
+///
+/// let queue = ArrayQueue::<i32, 0>::new(0);
+/// ```
 pub struct ArrayQueue<T: Copy, const N: usize> {
     data: [T; N],       // Directly stores T type data
     head: usize,        // Queue head index
@@ -29,6 +43,7 @@ impl<T: Copy, const N: usize> ArrayQueue<T, N> {
     /// Create a new empty queue
     /// Requires a default value to initialize the array
     pub fn new(default_value: T) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         Self {
             data: [default_value; N],
             head: 0,
@@ -37,6 +52,13 @@ impl<T: Copy, const N: usize> ArrayQueue<T, N> {
         }
     }
 
+    /// Memory footprint of this queue type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` for the slots plus three `usize` counters.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
     /// Check if the queue is empty
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -107,6 +129,33 @@ impl<T: Copy, const N: usize> ArrayQueue<T, N> {
         Some(&mut self.data[self.head])
     }
 
+    /// Get the element at position `index`, counted from the front (0)
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.data.get((self.head + index) % N)
+    }
+
+    /// Get the element at position `index` as a mutable reference
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.data.get_mut((self.head + index) % N)
+    }
+
+    /// Iterate over the elements from front to back without dequeuing them
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |i| &self.data[(self.head + i) % N])
+    }
+
     /// Clear the queue
     pub fn clear(&mut self) {
         self.head = 0;
@@ -125,6 +174,7 @@ pub struct AdvancedArrayQueue<T, const N: usize> {
 impl<T, const N: usize> AdvancedArrayQueue<T, N> {
     /// Create a new empty queue
     pub fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         // Safely initialize an array of N None values
         let data: [Option<T>; N] = {
             // Create an uninitialized array of MaybeUninit
@@ -145,6 +195,14 @@ impl<T, const N: usize> AdvancedArrayQueue<T, N> {
         }
     }
 
+    /// Memory footprint of this queue type in bytes
+    ///
+    /// Roughly `N * size_of::<Option<T>>()` for the slots plus three `usize`
+    /// counters; the `Option` tag is free for types with a niche.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
     /// Check if the queue is empty
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -206,6 +264,37 @@ impl<T, const N: usize> AdvancedArrayQueue<T, N> {
         self.data[self.head].as_mut()
     }
 
+    /// Get the element at position `index`, counted from the front (0)
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.data.get((self.head + index) % N)?.as_ref()
+    }
+
+    /// Get the element at position `index` as a mutable reference
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.data.get_mut((self.head + index) % N)?.as_mut()
+    }
+
+    /// Iterate over the elements from front to back without dequeuing them
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.len).map(move |i| match &self.data[(self.head + i) % N] {
+            Some(item) => item,
+            // Every slot between head and tail holds a value
+            None => unreachable!(),
+        })
+    }
+
     /// Clear the queue
     pub fn clear(&mut self) {
         while self.dequeue().is_some() {}
@@ -218,9 +307,135 @@ impl<T, const N: usize> Default for AdvancedArrayQueue<T, N> {
     }
 }
 
+impl<T: Copy, const N: usize> FixedCapacity for ArrayQueue<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for AdvancedArrayQueue<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Compares the queued elements from front to back
+impl<T: Copy + PartialEq, const N: usize> PartialEq for ArrayQueue<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && (0..self.len)
+                .all(|i| self.data[(self.head + i) % N] == other.data[(other.head + i) % N])
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for ArrayQueue<T, N> {}
+
+/// Hashes the queued elements from front to back, independent of where the
+/// ring buffer currently wraps
+impl<T: Copy + Hash, const N: usize> Hash for ArrayQueue<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for i in 0..self.len {
+            self.data[(self.head + i) % N].hash(state);
+        }
+    }
+}
+
+/// Compares the queued elements from front to back
+impl<T: PartialEq, const N: usize> PartialEq for AdvancedArrayQueue<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && (0..self.len)
+                .all(|i| self.data[(self.head + i) % N] == other.data[(other.head + i) % N])
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for AdvancedArrayQueue<T, N> {}
+
+/// Hashes the queued elements from front to back, independent of where the
+/// ring buffer currently wraps
+impl<T: Hash, const N: usize> Hash for AdvancedArrayQueue<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for i in 0..self.len {
+            self.data[(self.head + i) % N].hash(state);
+        }
+    }
+}
+
+/// Size of the length header written by `ArrayQueue::to_bytes`
+const HEADER_LEN: usize = 4;
+
+impl<T: NeBytes, const N: usize> ArrayQueue<T, N> {
+    /// Number of bytes `to_bytes` writes for the current contents
+    pub fn serialized_len(&self) -> usize {
+        HEADER_LEN + self.len * T::SIZE
+    }
+
+    /// Serialize the queue into `out` and return the number of bytes written
+    ///
+    /// The layout is the element count as a native-endian `u32`, followed by
+    /// the elements from front to back in native-endian byte order. It is
+    /// meant for persisting state on the same target, not for exchange
+    /// between machines of different endianness.
+    ///
+    /// # Errors
+    /// Returns `ArrayError::BufferTooSmall` if `out` is shorter than
+    /// `serialized_len()`.
+    pub fn to_bytes(&self, out: &mut [u8]) -> Result<usize, ArrayError> {
+        let total = self.serialized_len();
+        if out.len() < total {
+            return Err(ArrayError::BufferTooSmall);
+        }
+
+        (self.len as u32).write_ne(out);
+        for (chunk, &item) in out[HEADER_LEN..total]
+            .chunks_exact_mut(T::SIZE)
+            .zip(self.iter())
+        {
+            item.write_ne(chunk);
+        }
+        Ok(total)
+    }
+
+    /// Rebuild a queue from bytes produced by `to_bytes`
+    ///
+    /// Bytes past the encoded elements are ignored.
+    ///
+    /// # Errors
+    /// Returns `ArrayError::InvalidData` if the input is truncated or holds
+    /// more than `N` elements.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ArrayError> {
+        if data.len() < HEADER_LEN {
+            return Err(ArrayError::InvalidData);
+        }
+
+        let len = u32::read_ne(data) as usize;
+        if len > N || data.len() < HEADER_LEN + len * T::SIZE {
+            return Err(ArrayError::InvalidData);
+        }
+
+        let mut queue = Self::new(T::ZERO);
+        for chunk in data[HEADER_LEN..].chunks_exact(T::SIZE).take(len) {
+            // Cannot fail, `len <= N` was checked above
+            let _ = queue.enqueue(T::read_ne(chunk));
+        }
+        Ok(queue)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::hash_of;
 
     #[test]
     fn test_basic_operations() {
@@ -404,4 +619,168 @@ mod tests {
         queue.enqueue(5).unwrap();
         assert_eq!(queue.data, [4, 5, 3]);
     }
+
+    #[test]
+    fn test_hash_and_eq() {
+        // [2, 3] stored from slot 0 versus wrapped around from slot 2
+        let mut a = ArrayQueue::<i32, 3>::new(0);
+        a.enqueue(2).unwrap();
+        a.enqueue(3).unwrap();
+
+        let mut b = ArrayQueue::<i32, 3>::new(0);
+        for v in [0, 0, 2] {
+            b.enqueue(v).unwrap();
+        }
+        b.dequeue();
+        b.dequeue();
+        b.enqueue(3).unwrap();
+
+        assert!(a == b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        b.dequeue();
+        assert!(a != b);
+        assert_ne!(hash_of(&a), hash_of(&b));
+
+        let mut x: AdvancedArrayQueue<i32, 2> = AdvancedArrayQueue::new();
+        let mut y: AdvancedArrayQueue<i32, 2> = AdvancedArrayQueue::new();
+        x.enqueue(7).unwrap();
+        y.enqueue(1).unwrap();
+        y.enqueue(7).unwrap();
+        y.dequeue();
+        assert!(x == y);
+        assert_eq!(hash_of(&x), hash_of(&y));
+    }
+
+    #[test]
+    fn test_get() {
+        // Wrapped: front element sits in the last slot
+        let mut queue = ArrayQueue::<i32, 3>::new(0);
+        for v in [1, 2, 3] {
+            queue.enqueue(v).unwrap();
+        }
+        queue.dequeue();
+        queue.dequeue();
+        queue.enqueue(4).unwrap();
+
+        assert_eq!(queue.get(0), Some(&3));
+        assert_eq!(queue.get(1), Some(&4));
+        assert_eq!(queue.get(2), None);
+        if let Some(v) = queue.get_mut(1) {
+            *v = 40;
+        }
+        assert_eq!(queue.get(1), Some(&40));
+        assert_eq!(queue.get_mut(usize::MAX), None);
+
+        let mut queue: AdvancedArrayQueue<i32, 3> = AdvancedArrayQueue::new();
+        assert_eq!(queue.get(0), None);
+        for v in [1, 2, 3] {
+            queue.enqueue(v).unwrap();
+        }
+        queue.dequeue();
+        queue.enqueue(4).unwrap();
+
+        assert_eq!(queue.get(2), Some(&4));
+        assert_eq!(queue.get(3), None);
+        if let Some(v) = queue.get_mut(0) {
+            *v = 20;
+        }
+        assert_eq!(queue.peek(), Some(&20));
+        assert_eq!(queue.get_mut(3), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        // Wrapped layout: [4, 2, 3] with the front at slot 1
+        let mut queue = ArrayQueue::<i32, 3>::new(0);
+        for v in [1, 2, 3] {
+            queue.enqueue(v).unwrap();
+        }
+        queue.dequeue();
+        queue.enqueue(4).unwrap();
+
+        assert!(queue.iter().eq(&[2, 3, 4]));
+        assert!(queue.iter().rev().eq(&[4, 3, 2]));
+        assert_eq!(queue.iter().last(), Some(&4));
+
+        let mut iter = queue.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(&3));
+
+        let mut queue: AdvancedArrayQueue<i32, 3> = AdvancedArrayQueue::new();
+        for v in [1, 2, 3] {
+            queue.enqueue(v).unwrap();
+        }
+        queue.dequeue();
+        queue.dequeue();
+        queue.enqueue(4).unwrap();
+
+        assert!(queue.iter().rev().eq(&[4, 3]));
+        let mut iter = queue.iter();
+        iter.next();
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        // Wrapped queue so the logical order differs from the slot order
+        let mut queue = ArrayQueue::<i32, 4>::new(0);
+        for v in [-1, 2, 3, 4] {
+            queue.enqueue(v).unwrap();
+        }
+        queue.dequeue();
+        queue.dequeue();
+        queue.enqueue(i32::MAX).unwrap();
+
+        let mut buf = [0u8; 32];
+        assert_eq!(queue.to_bytes(&mut buf), Ok(4 + 3 * 4));
+
+        let restored = ArrayQueue::<i32, 4>::from_bytes(&buf).unwrap();
+        assert!(restored == queue);
+        assert!(restored.iter().eq(&[3, 4, i32::MAX]));
+
+        // Floats and an empty queue
+        let mut floats = ArrayQueue::<f32, 2>::new(0.0);
+        assert_eq!(floats.to_bytes(&mut buf), Ok(4));
+        assert!(
+            ArrayQueue::<f32, 2>::from_bytes(&buf[..4])
+                .unwrap()
+                .is_empty()
+        );
+        floats.enqueue(0.25).unwrap();
+        floats.to_bytes(&mut buf).unwrap();
+        assert_eq!(
+            ArrayQueue::<f32, 2>::from_bytes(&buf).unwrap().peek(),
+            Some(&0.25)
+        );
+    }
+
+    #[test]
+    fn test_bytes_errors() {
+        let mut queue = ArrayQueue::<u16, 3>::new(0);
+        queue.enqueue(7).unwrap();
+        queue.enqueue(8).unwrap();
+
+        let mut small = [0u8; 7];
+        assert_eq!(queue.to_bytes(&mut small), Err(ArrayError::BufferTooSmall));
+
+        let mut buf = [0u8; 8];
+        let written = queue.to_bytes(&mut buf).unwrap();
+        assert_eq!(
+            ArrayQueue::<u16, 3>::from_bytes(&buf[..written - 1]).err(),
+            Some(ArrayError::InvalidData)
+        );
+        // More elements than the target capacity
+        assert_eq!(
+            ArrayQueue::<u16, 1>::from_bytes(&buf).err(),
+            Some(ArrayError::InvalidData)
+        );
+        assert_eq!(
+            ArrayQueue::<u16, 3>::from_bytes(&[1, 0]).err(),
+            Some(ArrayError::InvalidData)
+        );
+    }
 }
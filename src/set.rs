@@ -0,0 +1,148 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use crate::vec::ArrayVec;
+
+/// Fixed-capacity set with linear-scan membership
+///
+/// Elements are kept in insertion order.
+pub struct ArraySet<T: PartialEq + Copy, const N: usize> {
+    items: ArrayVec<T, N>,
+}
+
+impl<T: PartialEq + Copy, const N: usize> ArraySet<T, N> {
+    /// Create a new empty set
+    pub const fn new() -> Self {
+        Self {
+            items: ArrayVec::new(),
+        }
+    }
+
+    /// Memory footprint of this set type in bytes
+    ///
+    /// Same as the backing `ArrayVec<T, N>`.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Add a value to the set
+    ///
+    /// Returns false if the value was already present or the set is full.
+    pub fn insert(&mut self, value: T) -> bool {
+        !self.contains(&value) && self.items.push(value).is_ok()
+    }
+
+    /// Check if the set holds `value`
+    pub fn contains(&self, value: &T) -> bool {
+        self.items.contains(value)
+    }
+
+    /// Remove `value` from the set
+    ///
+    /// Returns false if the value was not present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        match self.items.iter().position(|v| v == value) {
+            Some(index) => self.items.remove(index).is_some(),
+            None => false,
+        }
+    }
+
+    /// Check if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Check if the set is full
+    pub fn is_full(&self) -> bool {
+        self.items.is_full()
+    }
+
+    /// Return the current number of elements in the set
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Return the maximum number of elements the set can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Iterate over the elements in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.items.iter()
+    }
+
+    /// Clear the set
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+impl<T: PartialEq + Copy, const N: usize> Default for ArraySet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq + Copy, const N: usize> FixedCapacity for ArraySet<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicates() {
+        let mut set: ArraySet<i32, 4> = ArraySet::new();
+        assert!(set.insert(3));
+        assert!(set.insert(1));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&2));
+
+        // Deduplicate a slice
+        set.clear();
+        for v in [5, 5, 2, 5, 2, 7] {
+            set.insert(v);
+        }
+        assert!(set.iter().copied().eq([5, 2, 7]));
+    }
+
+    #[test]
+    fn test_remove_and_capacity() {
+        let mut set: ArraySet<u8, 2> = ArraySet::new();
+        assert!(set.insert(1));
+        assert!(set.insert(2));
+        assert!(set.is_full());
+        assert!(!set.insert(3)); // Set is full
+        assert!(!set.insert(2)); // Duplicates are still rejected
+
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert!(set.insert(3)); // Now there is space
+        assert!(set.iter().copied().eq([2, 3]));
+    }
+}
@@ -0,0 +1,83 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+/// Iterate over all overlapping windows of length `W`
+///
+/// Each item is a fixed-size array reference, so the window length is
+/// known at compile time; dereference it to copy out a `[T; W]` for `Copy`
+/// types. Yields nothing if `arr` is shorter than `W`.
+///
+/// `W` must be greater than zero, which is checked at compile time.
+pub fn windows<T, const W: usize>(arr: &[T]) -> impl Iterator<Item = &[T; W]> {
+    const { assert!(W > 0, "Window length W must be greater than zero") };
+    arr.windows(W).filter_map(|w| w.first_chunk::<W>())
+}
+
+/// Iterate over non-overlapping chunks of length `C`
+///
+/// Trailing elements that do not fill a whole chunk are skipped; use
+/// `chunks_remainder` to get at them.
+///
+/// `C` must be greater than zero, which is checked at compile time.
+pub fn chunks<T, const C: usize>(arr: &[T]) -> impl Iterator<Item = &[T; C]> {
+    const { assert!(C > 0, "Chunk length C must be greater than zero") };
+    arr.chunks_exact(C).filter_map(|c| c.first_chunk::<C>())
+}
+
+/// Trailing elements left over by `chunks`
+pub fn chunks_remainder<T, const C: usize>(arr: &[T]) -> &[T] {
+    const { assert!(C > 0, "Chunk length C must be greater than zero") };
+    &arr[arr.len() - arr.len() % C..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows() {
+        let arr = [1, 2, 3, 4, 5];
+        let mut iter = windows::<_, 3>(&arr);
+        assert_eq!(iter.next(), Some(&[1, 2, 3]));
+        assert_eq!(iter.next(), Some(&[2, 3, 4]));
+        assert_eq!(iter.next(), Some(&[3, 4, 5]));
+        assert_eq!(iter.next(), None);
+
+        // Copy out fixed-size arrays, e.g. for pairwise differences
+        let mut diffs = [0; 4];
+        for (d, [a, b]) in diffs.iter_mut().zip(windows::<_, 2>(&arr).copied()) {
+            *d = b - a;
+        }
+        assert_eq!(diffs, [1; 4]);
+
+        assert_eq!(windows::<_, 6>(&arr).count(), 0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let arr = [1, 2, 3, 4, 5, 6, 7];
+        let mut iter = chunks::<_, 3>(&arr);
+        assert_eq!(iter.next(), Some(&[1, 2, 3]));
+        assert_eq!(iter.next(), Some(&[4, 5, 6]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(chunks_remainder::<_, 3>(&arr), &[7]);
+
+        let sums = chunks::<_, 2>(&arr).map(|&[a, b]| a + b);
+        assert!(sums.eq([3, 7, 11]));
+        assert_eq!(chunks_remainder::<_, 7>(&arr), &[] as &[i32]);
+    }
+}
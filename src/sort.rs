@@ -0,0 +1,460 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::compare::{compare_items, compare_items_total};
+use crate::error::ArrayError;
+use crate::heap::sift_down;
+use core::cmp::Ordering;
+
+/// Sort bytes with a 256-entry histogram in O(n)
+///
+/// The sorted bytes are written to the front of `output`.
+///
+/// # Errors
+/// Returns `ArrayError::BufferTooSmall` if `output` is shorter than `input`.
+pub fn counting_sort(input: &[u8], output: &mut [u8]) -> Result<(), ArrayError> {
+    if output.len() < input.len() {
+        return Err(ArrayError::BufferTooSmall);
+    }
+
+    let mut counts = [0usize; 256];
+    for &byte in input {
+        counts[byte as usize] += 1;
+    }
+
+    let mut pos = 0;
+    for (byte, &count) in counts.iter().enumerate() {
+        output[pos..pos + count].fill(byte as u8);
+        pos += count;
+    }
+    Ok(())
+}
+
+/// Sort `u32` keys in place with an LSD radix sort
+///
+/// Makes four stable passes over 8-bit digits, bouncing the data between
+/// `arr` and `scratch`. The even pass count leaves the result in `arr`.
+///
+/// # Errors
+/// Returns `ArrayError::LengthMismatch` if `scratch` is not the same length
+/// as `arr`.
+pub fn radix_sort_u32(arr: &mut [u32], scratch: &mut [u32]) -> Result<(), ArrayError> {
+    if scratch.len() != arr.len() {
+        return Err(ArrayError::LengthMismatch);
+    }
+
+    let (mut src, mut dst) = (arr, scratch);
+    for shift in [0, 8, 16, 24] {
+        let mut offsets = [0usize; 256];
+        for &v in src.iter() {
+            offsets[(v >> shift) as usize & 0xFF] += 1;
+        }
+
+        // Turn digit counts into starting positions
+        let mut total = 0;
+        for slot in &mut offsets {
+            let count = *slot;
+            *slot = total;
+            total += count;
+        }
+
+        for &v in src.iter() {
+            let digit = (v >> shift) as usize & 0xFF;
+            dst[offsets[digit]] = v;
+            offsets[digit] += 1;
+        }
+
+        core::mem::swap(&mut src, &mut dst);
+    }
+    Ok(())
+}
+
+/// Stable in-place insertion sort with a custom comparator
+///
+/// O(n^2), but fast for short or nearly sorted slices.
+#[inline]
+pub fn insertion_sort<T: Copy, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], mut cmp: F) {
+    for i in 1..arr.len() {
+        let item = arr[i];
+        let mut j = i;
+        // Strictly greater only, so equal elements keep their order
+        while j > 0 && cmp(&arr[j - 1], &item) == Ordering::Greater {
+            arr[j] = arr[j - 1];
+            j -= 1;
+        }
+        arr[j] = item;
+    }
+}
+
+/// Sort in place with heapsort
+///
+/// Guaranteed O(n log n) without recursion. Not stable.
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    let len = arr.len();
+    // Heapify bottom-up, starting from the last parent
+    for i in (0..len / 2).rev() {
+        sift_down(arr, i, &mut T::lt);
+    }
+
+    // Move the max behind the shrinking heap
+    for end in (1..len).rev() {
+        arr.swap(0, end);
+        sift_down(&mut arr[..end], 0, &mut T::lt);
+    }
+}
+
+/// Ranges at or below this length are finished with insertion sort
+const QUICK_SORT_CUTOFF: usize = 16;
+
+/// Three-way partition around a median-of-three pivot
+///
+/// Returns `(lt, gt)` such that `arr[..lt]` is less than the pivot,
+/// `arr[lt..gt]` equal to it and `arr[gt..]` greater.
+fn partition3<T: PartialOrd + Copy>(arr: &mut [T]) -> (usize, usize) {
+    let (mut a, mut b, mut c) = (arr[0], arr[arr.len() / 2], arr[arr.len() - 1]);
+    if compare_items_total(b, a) == Ordering::Less {
+        core::mem::swap(&mut a, &mut b);
+    }
+    if compare_items_total(c, b) == Ordering::Less {
+        core::mem::swap(&mut b, &mut c);
+    }
+    if compare_items_total(b, a) == Ordering::Less {
+        b = a;
+    }
+    let pivot = b;
+
+    let (mut lt, mut i, mut gt) = (0, 0, arr.len());
+    while i < gt {
+        match compare_items_total(arr[i], pivot) {
+            Ordering::Less => {
+                arr.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                arr.swap(i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+/// Sort in place with an iterative quicksort
+///
+/// Uses a median-of-three pivot and a three-way partition, so sorted input
+/// and runs of equal keys stay fast. The smaller side is always handled
+/// first, which bounds the explicit range stack to `log2(len)` entries.
+/// NaN values are ordered before all other values. Not stable.
+pub fn quick_sort<T: PartialOrd + Copy>(arr: &mut [T]) {
+    let mut stack = [(0usize, 0usize); usize::BITS as usize];
+    let mut sp = 0;
+    let (mut lo, mut hi) = (0, arr.len());
+
+    loop {
+        while hi - lo > QUICK_SORT_CUTOFF {
+            let (lt, gt) = partition3(&mut arr[lo..hi]);
+            let (lt, gt) = (lo + lt, lo + gt);
+            // Defer the larger side, keep going on the smaller one
+            if lt - lo < hi - gt {
+                stack[sp] = (gt, hi);
+                hi = lt;
+            } else {
+                stack[sp] = (lo, lt);
+                lo = gt;
+            }
+            sp += 1;
+        }
+
+        insertion_sort(&mut arr[lo..hi], |a, b| compare_items_total(*a, *b));
+
+        if sp == 0 {
+            break;
+        }
+        sp -= 1;
+        (lo, hi) = stack[sp];
+    }
+}
+
+/// Sort in place with a stable bottom-up merge sort
+///
+/// Runs of doubling width are merged back and forth between `arr` and
+/// `scratch`, so no recursion is needed. NaN values are ordered before all
+/// other values.
+///
+/// # Errors
+/// Returns `ArrayError::LengthMismatch` if `scratch` is not the same length
+/// as `arr`.
+pub fn merge_sort<T: Copy + PartialOrd>(
+    arr: &mut [T],
+    scratch: &mut [T],
+) -> Result<(), ArrayError> {
+    if scratch.len() != arr.len() {
+        return Err(ArrayError::LengthMismatch);
+    }
+
+    let len = arr.len();
+    let mut in_scratch = false;
+    let mut width = 1;
+    while width < len {
+        let (src, dst): (&[T], &mut [T]) = if in_scratch {
+            (scratch, arr)
+        } else {
+            (arr, scratch)
+        };
+
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut i, mut j) = (start, mid);
+            for slot in &mut dst[start..end] {
+                // Take from the left run on ties to keep the sort stable
+                if j >= end || (i < mid && compare_items(src[j], src[i]) != Ordering::Less) {
+                    *slot = src[i];
+                    i += 1;
+                } else {
+                    *slot = src[j];
+                    j += 1;
+                }
+            }
+        }
+
+        in_scratch = !in_scratch;
+        width *= 2;
+    }
+
+    if in_scratch {
+        arr.copy_from_slice(scratch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift generator for test data
+    struct Rng(u32);
+
+    impl Rng {
+        fn next(&mut self) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_counting_sort() {
+        let mut rng = Rng(0x1234_5678);
+        let mut input = [0u8; 200];
+        for byte in &mut input {
+            *byte = rng.next() as u8;
+        }
+
+        let mut expected = input;
+        expected.sort_unstable();
+
+        let mut output = [0u8; 200];
+        assert_eq!(counting_sort(&input, &mut output), Ok(()));
+        assert_eq!(output, expected);
+
+        // Larger output buffers only have their front written
+        let mut output = [7u8; 5];
+        assert_eq!(counting_sort(&[255, 0, 3, 0], &mut output), Ok(()));
+        assert_eq!(output, [0, 0, 3, 255, 7]);
+
+        assert_eq!(counting_sort(&[], &mut []), Ok(()));
+        assert_eq!(
+            counting_sort(&input, &mut output),
+            Err(ArrayError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_radix_sort_u32() {
+        let mut rng = Rng(0xDEAD_BEEF);
+        let mut arr = [0u32; 300];
+        for v in &mut arr {
+            *v = rng.next();
+        }
+        let mut expected = arr;
+        expected.sort_unstable();
+
+        let mut scratch = [0u32; 300];
+        assert_eq!(radix_sort_u32(&mut arr, &mut scratch), Ok(()));
+        assert_eq!(arr, expected);
+
+        // Keys differing only in the top byte, duplicates and extremes
+        let mut arr = [
+            u32::MAX,
+            0x0100_0000,
+            0,
+            0xFF00_0000,
+            0x0100_0000,
+            1,
+            u32::MAX,
+        ];
+        let mut expected = arr;
+        expected.sort_unstable();
+        let mut scratch = [0u32; 7];
+        assert_eq!(radix_sort_u32(&mut arr, &mut scratch), Ok(()));
+        assert_eq!(arr, expected);
+
+        assert_eq!(
+            radix_sort_u32(&mut arr, &mut scratch[..6]),
+            Err(ArrayError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn test_insertion_sort() {
+        // Sort by key only; the tag records the original order
+        let mut pairs = [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e')];
+        insertion_sort(&mut pairs, |a, b| a.0.cmp(&b.0));
+        assert_eq!(pairs, [(1, 'b'), (1, 'e'), (2, 'd'), (3, 'a'), (3, 'c')]);
+
+        let mut arr = [4, 9, 1, 7, 1];
+        insertion_sort(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, [9, 7, 4, 1, 1]);
+
+        let mut empty: [i32; 0] = [];
+        insertion_sort(&mut empty, |a, b| a.cmp(b));
+    }
+
+    #[test]
+    fn test_heap_sort() {
+        let mut rng = Rng(0x0BAD_F00D);
+        for len in [0, 1, 2, 17, 128] {
+            let mut arr = [0i32; 128];
+            for v in &mut arr[..len] {
+                *v = (rng.next() % 50) as i32 - 25;
+            }
+            let mut expected = arr;
+            expected[..len].sort_unstable();
+
+            heap_sort(&mut arr[..len]);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_quick_sort() {
+        let mut sorted = [0i32; 100];
+        for (i, v) in sorted.iter_mut().enumerate() {
+            *v = i as i32;
+        }
+        let expected = sorted;
+
+        let mut arr = sorted;
+        quick_sort(&mut arr);
+        assert_eq!(arr, expected);
+
+        arr.reverse();
+        quick_sort(&mut arr);
+        assert_eq!(arr, expected);
+
+        let mut rng = Rng(0xC0FF_EE11);
+        let mut arr = [0u32; 500];
+        for v in &mut arr {
+            *v = rng.next() % 40; // Plenty of duplicates
+        }
+        let mut expected = arr;
+        expected.sort_unstable();
+        quick_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_quick_sort_nan() {
+        let mut arr = [0.0f32; 40];
+        for (i, v) in arr.iter_mut().enumerate() {
+            *v = (40 - i) as f32;
+        }
+        arr[7] = f32::NAN;
+        arr[30] = f32::NAN;
+
+        quick_sort(&mut arr);
+        assert!(arr[0].is_nan() && arr[1].is_nan());
+        assert!(arr[2..].windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(arr[2], 1.0);
+
+        // NaN runs longer than the insertion sort cutoff must still partition
+        let mut arr = [f32::NAN; 20];
+        quick_sort(&mut arr);
+        assert!(arr.iter().all(|v| v.is_nan()));
+
+        let mut arr = [f32::NAN; 48];
+        for i in (0..48).step_by(3) {
+            arr[i] = (48 - i) as f32;
+        }
+        quick_sort(&mut arr);
+        assert!(arr[..32].iter().all(|v| v.is_nan()));
+        assert!(arr[32..].windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_merge_sort() {
+        let mut rng = Rng(0x5EED_0001);
+        for len in [0, 1, 2, 3, 33, 64] {
+            let mut arr = [0i32; 64];
+            for v in &mut arr[..len] {
+                *v = (rng.next() % 1000) as i32;
+            }
+            let mut expected = arr;
+            expected[..len].sort_unstable();
+
+            let mut scratch = [0i32; 64];
+            assert_eq!(merge_sort(&mut arr[..len], &mut scratch[..len]), Ok(()));
+            assert_eq!(arr, expected);
+        }
+
+        let mut scratch = [0i32; 2];
+        assert_eq!(
+            merge_sort(&mut [3, 1, 2], &mut scratch),
+            Err(ArrayError::LengthMismatch)
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        key: u8,
+        id: u8,
+    }
+
+    impl PartialOrd for Record {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.key.partial_cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_stable() {
+        let keys = [2, 0, 1, 2, 0, 1, 2, 0, 1];
+        let mut records = [Record { key: 0, id: 0 }; 9];
+        for (id, (r, &key)) in records.iter_mut().zip(&keys).enumerate() {
+            *r = Record { key, id: id as u8 };
+        }
+
+        let mut scratch = records;
+        merge_sort(&mut records, &mut scratch).unwrap();
+        let ids = records.map(|r| r.id);
+        assert_eq!(ids, [1, 4, 7, 2, 5, 8, 0, 3, 6]);
+    }
+}
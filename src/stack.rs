@@ -15,9 +15,26 @@
 // with this program; if not, write to the Free Software Foundation, Inc.,
 // 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
 
+use crate::capacity::FixedCapacity;
+use crate::error::ArrayError;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
+use core::ops::Index;
 use core::ptr;
 
+/// Array-based stack implementation (optimized for Copy types)
+///
+/// `N` must be greater than zero, which is enforced at compile time for
+/// both stack types:
+///
+/// ```compile_fail
+/// use arraypp::stack::AdvancedArrayStack;
+///
+/// let stack = AdvancedArrayStack::<i32, 0>::new();
+/// ```
+#[derive(Clone)]
 pub struct ArrayStack<T: Copy, const N: usize> {
     data: [T; N], // Fixed-size array to store elements
     top: usize,   // Stack top pointer (points to the next free position)
@@ -26,16 +43,33 @@ pub struct ArrayStack<T: Copy, const N: usize> {
 impl<T: Copy, const N: usize> ArrayStack<T, N> {
     /// Create a new stack (requires initializing the array with a default value)
     pub const fn new(default_value: T) -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         ArrayStack {
             data: [default_value; N],
             top: 0,
         }
     }
 
+    /// Memory footprint of this stack type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` plus one `usize` for the top index.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Create a stack holding `items`, with the first element at the bottom
+    ///
+    /// `default_value` fills the unused slots as in `new`.
+    pub fn from_slice(items: &[T], default_value: T) -> Result<Self, ArrayError> {
+        let mut stack = Self::new(default_value);
+        stack.push_slice(items)?;
+        Ok(stack)
+    }
+
     /// Push operation
-    pub fn push(&mut self, item: T) -> Result<(), &'static str> {
+    pub fn push(&mut self, item: T) -> Result<(), ArrayError> {
         if self.is_full() {
-            Err("Stack full")
+            Err(ArrayError::Full)
         } else {
             self.data[self.top] = item;
             self.top += 1;
@@ -43,25 +77,100 @@ impl<T: Copy, const N: usize> ArrayStack<T, N> {
         }
     }
 
+    /// Push all elements of `items` in order
+    ///
+    /// Nothing is pushed if the elements do not all fit.
+    pub fn push_slice(&mut self, items: &[T]) -> Result<(), ArrayError> {
+        if items.len() > N - self.top {
+            return Err(ArrayError::Full);
+        }
+
+        self.data[self.top..self.top + items.len()].copy_from_slice(items);
+        self.top += items.len();
+        Ok(())
+    }
+
     /// Pop operation
-    pub fn pop(&mut self) -> Result<T, &'static str> {
+    pub fn pop(&mut self) -> Result<T, ArrayError> {
         if self.is_empty() {
-            Err("Stack empty")
+            Err(ArrayError::Empty)
         } else {
             self.top -= 1;
             Ok(self.data[self.top])
         }
     }
 
+    /// Pop up to `out.len()` elements into `out`, top element first
+    ///
+    /// Returns the number of elements popped.
+    pub fn pop_n(&mut self, out: &mut [T]) -> usize {
+        let count = out.len().min(self.top);
+        for slot in &mut out[..count] {
+            self.top -= 1;
+            *slot = self.data[self.top];
+        }
+        count
+    }
+
+    /// Pop operation returning `None` when the stack is empty
+    pub fn try_pop(&mut self) -> Option<T> {
+        self.pop().ok()
+    }
+
+    /// Pop the top element only if it satisfies `pred`
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        match self.peek() {
+            Ok(top) if pred(top) => self.try_pop(),
+            _ => None,
+        }
+    }
+
     /// Peek at the top element
-    pub fn peek(&self) -> Result<&T, &'static str> {
+    pub fn peek(&self) -> Result<&T, ArrayError> {
         if self.is_empty() {
-            Err("Stack empty")
+            Err(ArrayError::Empty)
         } else {
             Ok(&self.data[self.top - 1])
         }
     }
 
+    /// Peek at the bottom (earliest pushed) element
+    pub fn bottom(&self) -> Result<&T, ArrayError> {
+        if self.is_empty() {
+            Err(ArrayError::Empty)
+        } else {
+            Ok(&self.data[0])
+        }
+    }
+
+    /// Peek at the top element as a mutable reference
+    pub fn peek_mut(&mut self) -> Result<&mut T, ArrayError> {
+        if self.is_empty() {
+            Err(ArrayError::Empty)
+        } else {
+            Ok(&mut self.data[self.top - 1])
+        }
+    }
+
+    /// Peek at the `n`-th element below the top (`n = 0` is the top)
+    pub fn peek_from_top(&self, n: usize) -> Result<&T, ArrayError> {
+        if n >= self.top {
+            return Err(ArrayError::InvalidIndex);
+        }
+
+        Ok(&self.data[self.top - 1 - n])
+    }
+
+    /// Swap the two topmost elements
+    pub fn swap_top_two(&mut self) -> Result<(), ArrayError> {
+        if self.top < 2 {
+            return Err(ArrayError::NotEnoughElements);
+        }
+
+        self.data.swap(self.top - 1, self.top - 2);
+        Ok(())
+    }
+
     /// Check if the stack is empty
     pub fn is_empty(&self) -> bool {
         self.top == 0
@@ -76,6 +185,110 @@ impl<T: Copy, const N: usize> ArrayStack<T, N> {
     pub fn len(&self) -> usize {
         self.top
     }
+
+    /// Maximum number of elements the stack can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of elements that can still be pushed
+    pub fn remaining(&self) -> usize {
+        N - self.top
+    }
+
+    /// Get the element at position `index`, counted from the bottom (0)
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data[..self.top].get(index)
+    }
+
+    /// Get the element at position `index` as a mutable reference
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data[..self.top].get_mut(index)
+    }
+
+    /// Iterate over the elements from bottom to top without popping them
+    ///
+    /// Use `.rev()` to walk from the top down.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.data[..self.top].iter()
+    }
+
+    /// Remove all elements from the stack
+    pub fn clear(&mut self) {
+        self.top = 0;
+    }
+
+    /// Copy out the backing array together with the element count
+    ///
+    /// The first `count` slots hold the elements from bottom to top; the
+    /// remaining slots are unspecified.
+    pub fn to_array(&self) -> ([T; N], usize) {
+        (self.data, self.top)
+    }
+
+    /// Reverse the order of the elements in place (bottom becomes top)
+    pub fn reverse(&mut self) {
+        self.data[..self.top].reverse();
+    }
+
+    /// Clear the stack and return an iterator over the removed elements
+    ///
+    /// Elements are yielded from top to bottom, and the stack is empty even
+    /// if the iterator is not fully consumed.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        let len = self.top;
+        self.top = 0;
+        self.data[..len].iter().rev().copied()
+    }
+}
+
+/// Index access counted from the bottom of the stack (0)
+///
+/// # Panics
+/// Panics if `index >= len()`. Use `get` for a non-panicking alternative.
+impl<T: Copy, const N: usize> Index<usize> for ArrayStack<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!("Stack index out of range"),
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> ArrayStack<T, N> {
+    /// Check if the stack contains the given value
+    pub fn contains(&self, value: &T) -> bool {
+        self.data[..self.top].contains(value)
+    }
+}
+
+/// Formats the live elements from bottom to top
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for ArrayStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Compares the live elements from bottom to top
+impl<T: Copy + PartialEq, const N: usize> PartialEq for ArrayStack<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data[..self.top] == other.data[..other.top]
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for ArrayStack<T, N> {}
+
+/// Hashes the live elements from bottom to top, consistent with `PartialEq`
+impl<T: Copy + Hash, const N: usize> Hash for ArrayStack<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data[..self.top].hash(state);
+    }
 }
 
 pub struct AdvancedArrayStack<T, const N: usize> {
@@ -86,17 +299,36 @@ pub struct AdvancedArrayStack<T, const N: usize> {
 impl<T, const N: usize> AdvancedArrayStack<T, N> {
     /// Create a new empty stack
     pub const fn new() -> Self {
-        // Safety: MaybeUninit does not require initialization
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         AdvancedArrayStack {
-            data: unsafe { MaybeUninit::uninit().assume_init() },
+            data: [const { MaybeUninit::uninit() }; N],
             top: 0,
         }
     }
 
+    /// Memory footprint of this stack type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` plus one `usize` for the top index.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Create a stack by pushing every item of `iter` in order
+    ///
+    /// Fails if the iterator yields more than `N` items; the items pushed so
+    /// far are dropped along with the partially built stack.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ArrayError> {
+        let mut stack = Self::new();
+        for item in iter {
+            stack.push(item)?;
+        }
+        Ok(stack)
+    }
+
     /// Push operation
-    pub fn push(&mut self, item: T) -> Result<(), &'static str> {
+    pub fn push(&mut self, item: T) -> Result<(), ArrayError> {
         if self.is_full() {
-            return Err("Stack full");
+            return Err(ArrayError::Full);
         }
 
         // Write data
@@ -106,9 +338,9 @@ impl<T, const N: usize> AdvancedArrayStack<T, N> {
     }
 
     /// Pop operation
-    pub fn pop(&mut self) -> Result<T, &'static str> {
+    pub fn pop(&mut self) -> Result<T, ArrayError> {
         if self.is_empty() {
-            return Err("Stack empty");
+            return Err(ArrayError::Empty);
         }
 
         self.top -= 1;
@@ -116,26 +348,69 @@ impl<T, const N: usize> AdvancedArrayStack<T, N> {
         Ok(unsafe { self.data[self.top].assume_init_read() })
     }
 
+    /// Pop operation returning `None` when the stack is empty
+    pub fn try_pop(&mut self) -> Option<T> {
+        self.pop().ok()
+    }
+
+    /// Pop the top element only if it satisfies `pred`
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        match self.peek() {
+            Ok(top) if pred(top) => self.try_pop(),
+            _ => None,
+        }
+    }
+
     /// Peek at the top element
-    pub fn peek(&self) -> Result<&T, &'static str> {
+    pub fn peek(&self) -> Result<&T, ArrayError> {
         if self.is_empty() {
-            return Err("Stack empty");
+            return Err(ArrayError::Empty);
         }
 
         // Safety: We know this position is initialized
         Ok(unsafe { &*self.data[self.top - 1].as_ptr() })
     }
 
+    /// Peek at the bottom (earliest pushed) element
+    pub fn bottom(&self) -> Result<&T, ArrayError> {
+        if self.is_empty() {
+            return Err(ArrayError::Empty);
+        }
+
+        // Safety: We know this position is initialized
+        Ok(unsafe { self.data[0].assume_init_ref() })
+    }
+
     /// Peek at the top element as a mutable reference
-    pub fn peek_mut(&mut self) -> Result<&mut T, &'static str> {
+    pub fn peek_mut(&mut self) -> Result<&mut T, ArrayError> {
         if self.is_empty() {
-            return Err("Stack empty");
+            return Err(ArrayError::Empty);
         }
 
         // Safety: We know this position is initialized
         Ok(unsafe { &mut *self.data[self.top - 1].as_mut_ptr() })
     }
 
+    /// Peek at the `n`-th element below the top (`n = 0` is the top)
+    pub fn peek_from_top(&self, n: usize) -> Result<&T, ArrayError> {
+        if n >= self.top {
+            return Err(ArrayError::InvalidIndex);
+        }
+
+        // Safety: Positions below `top` are initialized
+        Ok(unsafe { self.data[self.top - 1 - n].assume_init_ref() })
+    }
+
+    /// Swap the two topmost elements
+    pub fn swap_top_two(&mut self) -> Result<(), ArrayError> {
+        if self.top < 2 {
+            return Err(ArrayError::NotEnoughElements);
+        }
+
+        self.data.swap(self.top - 1, self.top - 2);
+        Ok(())
+    }
+
     /// Check if the stack is empty
     pub fn is_empty(&self) -> bool {
         self.top == 0
@@ -151,12 +426,149 @@ impl<T, const N: usize> AdvancedArrayStack<T, N> {
         self.top
     }
 
+    /// Maximum number of elements the stack can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of elements that can still be pushed
+    pub fn remaining(&self) -> usize {
+        N - self.top
+    }
+
+    /// Remove and drop all elements in the stack
+    pub fn clear(&mut self) {
+        let len = self.top;
+        // Reset first so a panicking destructor leaks instead of double-dropping
+        self.top = 0;
+        for i in 0..len {
+            unsafe {
+                ptr::drop_in_place(self.data[i].as_mut_ptr());
+            }
+        }
+    }
+
+    /// View the elements as a slice, from bottom to top
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: Positions below `top` are initialized, and
+        // `MaybeUninit<T>` has the same layout as `T`
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.top) }
+    }
+
+    /// View the elements as a mutable slice, from bottom to top
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: Same as `as_slice`
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.top) }
+    }
+
+    /// Get the element at position `index`, counted from the bottom (0)
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Get the element at position `index` as a mutable reference
+    ///
+    /// Returns None instead of panicking when `index` is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Iterate over the elements from bottom to top without popping them
+    ///
+    /// Use `.rev()` to walk from the top down.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.as_slice().iter()
+    }
+
+    /// Keep only the elements matching `pred`, preserving their order
+    ///
+    /// Rejected elements are dropped in place and the survivors are
+    /// compacted towards the bottom of the stack.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut pred: F) {
+        let len = self.top;
+        // Reset first so a panicking predicate leaks instead of double-dropping
+        self.top = 0;
+        let mut kept = 0;
+
+        for i in 0..len {
+            // Safety: Positions below `len` are initialized, and every slot
+            // is either moved down or dropped exactly once
+            unsafe {
+                if pred(self.data[i].assume_init_ref()) {
+                    if i != kept {
+                        let item = self.data[i].assume_init_read();
+                        self.data[kept].write(item);
+                    }
+                    kept += 1;
+                } else {
+                    ptr::drop_in_place(self.data[i].as_mut_ptr());
+                }
+            }
+        }
+
+        self.top = kept;
+    }
+
+    /// Move the elements at positions `at..len()` into a new stack
+    ///
+    /// The first `at` elements stay in place; the moved elements keep their
+    /// bottom-to-top order in the returned stack.
+    pub fn split_off(&mut self, at: usize) -> Result<AdvancedArrayStack<T, N>, ArrayError> {
+        if at > self.top {
+            return Err(ArrayError::InvalidIndex);
+        }
+
+        let mut other = Self::new();
+        for i in at..self.top {
+            // Safety: Positions below `top` are initialized; ownership moves
+            // to `other` and `top` is lowered so nothing is dropped twice
+            let item = unsafe { self.data[i].assume_init_read() };
+            other.data[i - at].write(item);
+        }
+        other.top = self.top - at;
+        self.top = at;
+
+        Ok(other)
+    }
+
     /// Clear the stack and return an iterator
     pub fn drain(&mut self) -> Drain<'_, T, N> {
         Drain { stack: self }
     }
 }
 
+impl<T: PartialEq, const N: usize> AdvancedArrayStack<T, N> {
+    /// Check if the stack contains the given value
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|item| item == value)
+    }
+}
+
+/// Formats the live elements from bottom to top
+impl<T: fmt::Debug, const N: usize> fmt::Debug for AdvancedArrayStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Compares the live elements from bottom to top
+impl<T: PartialEq, const N: usize> PartialEq for AdvancedArrayStack<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for AdvancedArrayStack<T, N> {}
+
+/// Hashes the live elements from bottom to top, consistent with `PartialEq`
+impl<T: Hash, const N: usize> Hash for AdvancedArrayStack<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
 impl<T, const N: usize> Default for AdvancedArrayStack<T, N> {
     fn default() -> Self {
         Self::new()
@@ -199,10 +611,244 @@ impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
     }
 }
 
+/// Owning iterator yielding the elements of a stack from top to bottom
+pub struct IntoIter<T, const N: usize> {
+    stack: AdvancedArrayStack<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Popped elements are no longer owned by the stack, so its Drop
+        // only releases the ones that were not yielded
+        self.stack.pop().ok()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for AdvancedArrayStack<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { stack: self }
+    }
+}
+
+/// Stack that reports its minimum element in O(1)
+///
+/// Keeps a second stack of running minima in parallel with the values, so
+/// `push`, `pop` and `min` never scan the elements.
+pub struct MinStack<T: Copy + Ord, const N: usize> {
+    values: ArrayStack<T, N>,
+    minima: ArrayStack<T, N>,
+}
+
+impl<T: Copy + Ord, const N: usize> MinStack<T, N> {
+    /// Create a new stack (requires initializing the array with a default value)
+    pub const fn new(default_value: T) -> Self {
+        MinStack {
+            values: ArrayStack::new(default_value),
+            minima: ArrayStack::new(default_value),
+        }
+    }
+
+    /// Memory footprint of this stack type in bytes
+    ///
+    /// Roughly `2 * N * size_of::<T>()`, one array for the values and one for
+    /// the running minima, plus their lengths.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Push operation
+    pub fn push(&mut self, item: T) -> Result<(), ArrayError> {
+        let min = match self.minima.peek() {
+            Ok(&current) if current < item => current,
+            _ => item,
+        };
+        self.values.push(item)?;
+        self.minima.push(min)
+    }
+
+    /// Pop operation
+    pub fn pop(&mut self) -> Result<T, ArrayError> {
+        self.minima.pop()?;
+        self.values.pop()
+    }
+
+    /// Peek at the top element
+    pub fn peek(&self) -> Result<&T, ArrayError> {
+        self.values.peek()
+    }
+
+    /// Smallest element currently in the stack
+    pub fn min(&self) -> Option<&T> {
+        self.minima.peek().ok()
+    }
+
+    /// Check if the stack is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Check if the stack is full
+    pub fn is_full(&self) -> bool {
+        self.values.is_full()
+    }
+
+    /// Current number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+/// Stack that reports its maximum element in O(1)
+///
+/// Keeps a second stack of running maxima in parallel with the values, so
+/// `push`, `pop` and `max` never scan the elements.
+pub struct MaxStack<T: Copy + Ord, const N: usize> {
+    values: ArrayStack<T, N>,
+    maxima: ArrayStack<T, N>,
+}
+
+impl<T: Copy + Ord, const N: usize> MaxStack<T, N> {
+    /// Create a new stack (requires initializing the array with a default value)
+    pub const fn new(default_value: T) -> Self {
+        MaxStack {
+            values: ArrayStack::new(default_value),
+            maxima: ArrayStack::new(default_value),
+        }
+    }
+
+    /// Memory footprint of this stack type in bytes
+    ///
+    /// Roughly `2 * N * size_of::<T>()`, one array for the values and one for
+    /// the running maxima, plus their lengths.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Push operation
+    pub fn push(&mut self, item: T) -> Result<(), ArrayError> {
+        let max = match self.maxima.peek() {
+            Ok(&current) if current > item => current,
+            _ => item,
+        };
+        self.values.push(item)?;
+        self.maxima.push(max)
+    }
+
+    /// Pop operation
+    pub fn pop(&mut self) -> Result<T, ArrayError> {
+        self.maxima.pop()?;
+        self.values.pop()
+    }
+
+    /// Peek at the top element
+    pub fn peek(&self) -> Result<&T, ArrayError> {
+        self.values.peek()
+    }
+
+    /// Largest element currently in the stack
+    pub fn max(&self) -> Option<&T> {
+        self.maxima.peek().ok()
+    }
+
+    /// Check if the stack is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Check if the stack is full
+    pub fn is_full(&self) -> bool {
+        self.values.is_full()
+    }
+
+    /// Current number of elements in the stack
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+/// For each element, find the next element to its right that is strictly greater
+///
+/// `out[i]` receives that element, or `None` when no greater element follows.
+/// Runs in O(n) using a monotonic stack of pending indices. Without an
+/// allocator that stack lives in `scratch`, which the caller provides.
+///
+/// # Return value
+/// - `Ok(())`: `out` holds the result for every element of `arr`
+/// - `Err(ArrayError::BufferTooSmall)`: `out` or `scratch` is shorter than `arr`
+pub fn next_greater_elements<T: PartialOrd + Copy>(
+    arr: &[T],
+    out: &mut [Option<T>],
+    scratch: &mut [usize],
+) -> Result<(), ArrayError> {
+    if out.len() < arr.len() || scratch.len() < arr.len() {
+        return Err(ArrayError::BufferTooSmall);
+    }
+
+    // Indices still waiting for a greater element, with non-increasing values
+    let pending = scratch;
+    let mut top = 0;
+
+    for (i, &item) in arr.iter().enumerate() {
+        while top > 0 {
+            let waiting = pending[top - 1];
+            if item.partial_cmp(&arr[waiting]) != Some(Ordering::Greater) {
+                break;
+            }
+            out[waiting] = Some(item);
+            top -= 1;
+        }
+        out[i] = None;
+        pending[top] = i;
+        top += 1;
+    }
+
+    Ok(())
+}
+
+impl<T: Copy, const N: usize> FixedCapacity for ArrayStack<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.top
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for AdvancedArrayStack<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.top
+    }
+}
+
 // Test cases
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{DropCounter, TextBuf, hash_of};
+    use core::cell::Cell;
+    use core::fmt::Write;
+
+    /// Value that counts its drops in a shared cell
+    struct Tracked<'a> {
+        value: i32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Tracked<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
 
     #[test]
     fn basic_operations() {
@@ -222,12 +868,680 @@ mod tests {
         assert!(stack.push(4).is_ok());
         assert!(stack.is_full());
 
-        assert_eq!(stack.push(5), Err("Stack full"));
+        assert_eq!(stack.push(5), Err(ArrayError::Full));
 
         assert_eq!(stack.pop(), Ok(4));
         assert_eq!(stack.pop(), Ok(3));
         assert_eq!(stack.peek(), Ok(&1));
         assert_eq!(stack.pop(), Ok(1));
-        assert_eq!(stack.pop(), Err("Stack empty"));
+        assert_eq!(stack.pop(), Err(ArrayError::Empty));
+    }
+
+    #[test]
+    fn iter_bottom_to_top() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+
+        let mut iter = stack.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        // Iteration does not consume the stack
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Ok(&3));
+    }
+
+    #[test]
+    fn peek_mut_updates_top() {
+        let mut stack: ArrayStack<i32, 3> = ArrayStack::new(0);
+        assert_eq!(stack.peek_mut(), Err(ArrayError::Empty));
+
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        *stack.peek_mut().unwrap() += 40;
+
+        assert_eq!(stack.pop(), Ok(42));
+        assert_eq!(stack.pop(), Ok(1));
+    }
+
+    #[test]
+    fn clear_both_stacks() {
+        let mut stack: ArrayStack<i32, 3> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.clear();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), Err(ArrayError::Empty));
+
+        let drops = Cell::new(0);
+        let mut stack: AdvancedArrayStack<DropCounter, 4> = AdvancedArrayStack::new();
+        for _ in 0..3 {
+            assert!(stack.push(DropCounter(&drops)).is_ok());
+        }
+        stack.clear();
+        assert_eq!(drops.get(), 3);
+        assert!(stack.is_empty());
+
+        // Dropping the cleared stack must not drop anything again
+        drop(stack);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn drain_array_stack() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+
+        let mut drained = [0; 3];
+        for (slot, value) in drained.iter_mut().zip(stack.drain()) {
+            *slot = value;
+        }
+        assert_eq!(drained, [3, 2, 1]);
+        assert!(stack.is_empty());
+
+        // A partially consumed drain still empties the stack
+        stack.push(4).unwrap();
+        stack.push(5).unwrap();
+        assert_eq!(stack.drain().next(), Some(5));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn index_and_get() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(10).unwrap();
+        stack.push(20).unwrap();
+        stack.push(30).unwrap();
+
+        assert_eq!(stack[0], 10);
+        assert_eq!(stack[2], 30);
+        assert_eq!(stack.get(1), Some(&20));
+        // Slot 3 holds the default value but is not part of the stack
+        assert_eq!(stack.get(3), None);
+        assert_eq!(stack.get(100), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Stack index out of range")]
+    fn index_out_of_range() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(10).unwrap();
+        let _ = stack[1];
+    }
+
+    #[test]
+    fn contains_on_both_stacks() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(7);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(stack.contains(&1));
+        assert!(stack.contains(&2));
+        // The default value filling unused slots is not a member
+        assert!(!stack.contains(&7));
+
+        let mut stack: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        assert!(!stack.contains(&1));
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(stack.contains(&1));
+        assert!(!stack.contains(&3));
+        stack.pop().unwrap();
+        assert!(!stack.contains(&2));
+    }
+
+    #[test]
+    fn pop_n_into_buffer() {
+        let mut stack: ArrayStack<i32, 5> = ArrayStack::new(0);
+        for i in 1..=5 {
+            stack.push(i).unwrap();
+        }
+
+        let mut out = [0; 3];
+        assert_eq!(stack.pop_n(&mut out), 3);
+        assert_eq!(out, [5, 4, 3]);
+        assert_eq!(stack.len(), 2);
+
+        // Only the remaining elements are popped
+        let mut out = [0; 3];
+        assert_eq!(stack.pop_n(&mut out), 2);
+        assert_eq!(out, [2, 1, 0]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn push_slice_all_or_nothing() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+
+        assert_eq!(stack.push_slice(&[2, 3]), Ok(()));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Ok(&3));
+
+        assert_eq!(stack.push_slice(&[4, 5]), Err(ArrayError::Full));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Ok(&3));
+
+        assert_eq!(stack.push_slice(&[4]), Ok(()));
+        assert!(stack.is_full());
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let mut stack: ArrayStack<i32, 3> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+
+        let mut snapshot = stack.clone();
+        assert_eq!(snapshot.pop(), Ok(2));
+        assert_eq!(snapshot.pop(), Ok(1));
+        assert!(snapshot.is_empty());
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.pop(), Ok(2));
+    }
+
+    #[test]
+    fn swap_top_two_elements() {
+        let mut stack: ArrayStack<i32, 3> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        assert_eq!(stack.swap_top_two(), Err(ArrayError::NotEnoughElements));
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+        assert_eq!(stack.swap_top_two(), Ok(()));
+        assert_eq!(stack.pop(), Ok(2));
+        assert_eq!(stack.pop(), Ok(3));
+        assert_eq!(stack.pop(), Ok(1));
+
+        let mut stack: AdvancedArrayStack<i32, 3> = AdvancedArrayStack::new();
+        stack.push(1).unwrap();
+        assert_eq!(stack.swap_top_two(), Err(ArrayError::NotEnoughElements));
+        stack.push(2).unwrap();
+        assert_eq!(stack.swap_top_two(), Ok(()));
+        assert_eq!(stack.pop(), Ok(1));
+        assert_eq!(stack.pop(), Ok(2));
+    }
+
+    #[test]
+    fn capacity_and_remaining() {
+        let mut stack: ArrayStack<i32, 3> = ArrayStack::new(0);
+        let mut advanced: AdvancedArrayStack<i32, 3> = AdvancedArrayStack::new();
+
+        for pushed in 0..3 {
+            assert_eq!(stack.capacity(), 3);
+            assert_eq!(stack.remaining(), 3 - pushed);
+            assert_eq!(advanced.capacity(), 3);
+            assert_eq!(advanced.remaining(), 3 - pushed);
+            stack.push(1).unwrap();
+            advanced.push(1).unwrap();
+        }
+
+        assert_eq!(stack.remaining(), 0);
+        assert_eq!(advanced.remaining(), 0);
+    }
+
+    #[test]
+    fn into_iter_consumes_stack() {
+        let mut stack: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+
+        let mut collected = [0; 3];
+        for (slot, value) in collected.iter_mut().zip(stack) {
+            *slot = value;
+        }
+        assert_eq!(collected, [3, 2, 1]);
+
+        let drops = Cell::new(0);
+        let mut stack: AdvancedArrayStack<DropCounter, 4> = AdvancedArrayStack::new();
+        for _ in 0..3 {
+            assert!(stack.push(DropCounter(&drops)).is_ok());
+        }
+        let mut iter = stack.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 1);
+        // Dropping the iterator releases the remaining elements exactly once
+        drop(iter);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn debug_formatting() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+        let mut buf = TextBuf::new();
+        write!(buf, "{:?}", stack).unwrap();
+        assert_eq!(buf.as_str(), "[1, 2, 3]");
+
+        let mut stack: AdvancedArrayStack<&str, 4> = AdvancedArrayStack::new();
+        let mut buf = TextBuf::new();
+        write!(buf, "{:?}", stack).unwrap();
+        assert_eq!(buf.as_str(), "[]");
+
+        stack.push("a").unwrap();
+        stack.push("b").unwrap();
+        let mut buf = TextBuf::new();
+        write!(buf, "{:?}", stack).unwrap();
+        assert_eq!(buf.as_str(), r#"["a", "b"]"#);
+    }
+
+    #[test]
+    fn from_slice_constructor() {
+        let mut stack = ArrayStack::<i32, 4>::from_slice(&[1, 2, 3], 0).unwrap();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Ok(3));
+        assert_eq!(stack.pop(), Ok(2));
+        assert_eq!(stack.pop(), Ok(1));
+        assert!(stack.is_empty());
+
+        assert!(ArrayStack::<i32, 2>::from_slice(&[1, 2, 3], 0).is_err());
+    }
+
+    #[test]
+    fn reverse_in_place() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+        stack.reverse();
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Ok(1));
+        assert_eq!(stack.pop(), Ok(2));
+        assert_eq!(stack.pop(), Ok(3));
+    }
+
+    #[test]
+    fn min_stack_tracks_minimum() {
+        let mut stack: MinStack<i32, 16> = MinStack::new(0);
+        assert_eq!(stack.min(), None);
+
+        // Interleave pushes and pops driven by a small LCG
+        let mut seed: u32 = 12345;
+        for _ in 0..200 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let value = (seed >> 16) as i32 % 50 - 25;
+            if (seed >> 8).is_multiple_of(3) || stack.is_full() {
+                let _ = stack.pop();
+            } else {
+                stack.push(value).unwrap();
+            }
+            assert_eq!(stack.min(), stack.values.iter().min());
+        }
+
+        stack.push(i32::MIN).unwrap();
+        assert_eq!(stack.min(), Some(&i32::MIN));
+        assert_eq!(stack.pop(), Ok(i32::MIN));
+    }
+
+    #[test]
+    fn try_from_iter_fit_and_overflow() {
+        let mut stack = AdvancedArrayStack::<i32, 3>::try_from_iter([1, 2, 3]).unwrap();
+        assert!(stack.is_full());
+        assert_eq!(stack.pop(), Ok(3));
+
+        let drops = Cell::new(0);
+        let items = [
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+        ];
+        let result = AdvancedArrayStack::<DropCounter, 3>::try_from_iter(items);
+        assert!(result.is_err());
+        // Pushed items and the rejected one are each dropped exactly once
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn retain_even_values() {
+        let drops = Cell::new(0);
+        let mut stack: AdvancedArrayStack<Tracked, 8> = AdvancedArrayStack::new();
+        for value in 1..=6 {
+            assert!(
+                stack
+                    .push(Tracked {
+                        value,
+                        drops: &drops
+                    })
+                    .is_ok()
+            );
+        }
+
+        stack.retain(|t| t.value % 2 == 0);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(drops.get(), 3);
+
+        assert_eq!(stack.pop().map(|t| t.value), Ok(6));
+        assert_eq!(stack.pop().map(|t| t.value), Ok(4));
+        assert_eq!(stack.peek().map(|t| t.value), Ok(2));
+
+        drop(stack);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn peek_from_top_depths() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        let mut advanced: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        for value in [10, 20, 30] {
+            stack.push(value).unwrap();
+            advanced.push(value).unwrap();
+        }
+
+        for (n, expected) in [(0, 30), (1, 20), (2, 10)] {
+            assert_eq!(stack.peek_from_top(n), Ok(&expected));
+            assert_eq!(advanced.peek_from_top(n), Ok(&expected));
+        }
+        assert_eq!(stack.peek_from_top(3), Err(ArrayError::InvalidIndex));
+        assert_eq!(advanced.peek_from_top(3), Err(ArrayError::InvalidIndex));
+    }
+
+    #[test]
+    fn to_array_snapshot() {
+        let mut stack: ArrayStack<i32, 5> = ArrayStack::new(0);
+        stack.push(7).unwrap();
+        stack.push(8).unwrap();
+        stack.push(9).unwrap();
+
+        let (data, count) = stack.to_array();
+        assert_eq!(count, 3);
+        assert_eq!(data[..count], [7, 8, 9]);
+
+        // The snapshot is unaffected by later changes
+        stack.pop().unwrap();
+        assert_eq!(data[2], 9);
+    }
+
+    #[test]
+    fn advanced_iter_bottom_to_top() {
+        let mut stack: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+
+        let mut iter = stack.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Ok(&3));
+    }
+
+    #[test]
+    fn sort_through_as_mut_slice() {
+        let mut stack: AdvancedArrayStack<i32, 5> = AdvancedArrayStack::new();
+        for value in [4, 1, 5, 2] {
+            stack.push(value).unwrap();
+        }
+
+        stack.as_mut_slice().sort_unstable();
+        assert_eq!(stack.as_slice(), &[1, 2, 4, 5]);
+        assert_eq!(stack.pop(), Ok(5));
+        assert_eq!(stack.as_slice(), &[1, 2, 4]);
+
+        let empty: AdvancedArrayStack<i32, 5> = AdvancedArrayStack::new();
+        assert!(empty.as_slice().is_empty());
+    }
+
+    #[test]
+    fn split_off_moves_upper_half() {
+        let drops = Cell::new(0);
+        let mut stack: AdvancedArrayStack<Tracked, 4> = AdvancedArrayStack::new();
+        for value in 1..=4 {
+            assert!(
+                stack
+                    .push(Tracked {
+                        value,
+                        drops: &drops
+                    })
+                    .is_ok()
+            );
+        }
+
+        assert!(stack.split_off(5).is_err());
+
+        let upper = stack.split_off(2).unwrap();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(upper.len(), 2);
+        assert_eq!(stack.peek().map(|t| t.value), Ok(2));
+        assert_eq!(upper.peek().map(|t| t.value), Ok(4));
+        assert_eq!(upper.iter().next().map(|t| t.value), Some(3));
+
+        drop(upper);
+        assert_eq!(drops.get(), 2);
+        drop(stack);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn next_greater() {
+        let mut scratch = [0usize; 8];
+        let mut out = [Some(0); 3];
+        assert_eq!(
+            next_greater_elements(&[2, 1, 3], &mut out, &mut scratch),
+            Ok(())
+        );
+        assert_eq!(out, [Some(3), Some(3), None]);
+
+        let arr = [4, 5, 2, 2, 25, 1];
+        let mut out = [None; 6];
+        assert_eq!(next_greater_elements(&arr, &mut out, &mut scratch), Ok(()));
+        assert_eq!(out, [Some(5), Some(25), Some(25), Some(25), None, None]);
+
+        // Works on a sub-slice, not just whole arrays
+        let mut out = [None; 2];
+        assert_eq!(
+            next_greater_elements(&arr[3..5], &mut out, &mut scratch),
+            Ok(())
+        );
+        assert_eq!(out, [Some(25), None]);
+
+        let mut small = [None; 2];
+        assert_eq!(
+            next_greater_elements(&[2, 1, 3], &mut small, &mut scratch),
+            Err(ArrayError::BufferTooSmall)
+        );
+        let mut out = [None; 3];
+        assert_eq!(
+            next_greater_elements(&[2, 1, 3], &mut out, &mut scratch[..2]),
+            Err(ArrayError::BufferTooSmall)
+        );
+
+        let mut empty: [Option<i32>; 0] = [];
+        assert_eq!(next_greater_elements(&[], &mut empty, &mut []), Ok(()));
+    }
+
+    #[test]
+    fn stack_equality() {
+        // Different default values in the unused slots do not matter
+        let a = ArrayStack::<i32, 4>::from_slice(&[1, 2], 0).unwrap();
+        let mut b = ArrayStack::<i32, 4>::from_slice(&[1, 2, 3], 9).unwrap();
+        assert!(a != b);
+        b.pop().unwrap();
+        assert!(a == b);
+
+        let mut a: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        let mut b: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        assert!(a == b);
+        a.push(1).unwrap();
+        b.push(2).unwrap();
+        assert!(a != b);
+        b.pop().unwrap();
+        b.push(1).unwrap();
+        assert!(a == b);
+    }
+
+    #[test]
+    fn max_stack_tracks_maximum() {
+        let mut stack: MaxStack<i32, 16> = MaxStack::new(0);
+        assert_eq!(stack.max(), None);
+
+        // Interleave pushes and pops driven by a small LCG
+        let mut seed: u32 = 54321;
+        for _ in 0..200 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let value = (seed >> 16) as i32 % 50 - 25;
+            if (seed >> 8).is_multiple_of(3) || stack.is_full() {
+                let _ = stack.pop();
+            } else {
+                stack.push(value).unwrap();
+            }
+            assert_eq!(stack.max(), stack.values.iter().max());
+        }
+
+        stack.push(i32::MAX).unwrap();
+        assert_eq!(stack.max(), Some(&i32::MAX));
+        assert_eq!(stack.pop(), Ok(i32::MAX));
+    }
+
+    #[test]
+    fn new_with_non_copy_type() {
+        const EMPTY: AdvancedArrayStack<Tracked<'static>, 3> = AdvancedArrayStack::new();
+        let stack = EMPTY;
+        assert!(stack.is_empty());
+
+        let drops = Cell::new(0);
+        let mut stack: AdvancedArrayStack<Tracked, 3> = AdvancedArrayStack::new();
+        for value in 1..=3 {
+            assert!(
+                stack
+                    .push(Tracked {
+                        value,
+                        drops: &drops
+                    })
+                    .is_ok()
+            );
+        }
+        assert_eq!(stack.pop().map(|t| t.value), Ok(3));
+        assert_eq!(stack.pop().map(|t| t.value), Ok(2));
+        assert_eq!(drops.get(), 2);
+        drop(stack);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn try_pop_loop() {
+        let mut stack = ArrayStack::<i32, 4>::from_slice(&[1, 2, 3], 0).unwrap();
+        let mut sum = 0;
+        while let Some(value) = stack.try_pop() {
+            sum = sum * 10 + value;
+        }
+        assert_eq!(sum, 321);
+        assert!(stack.is_empty());
+
+        let mut stack = AdvancedArrayStack::<i32, 4>::try_from_iter([1, 2, 3]).unwrap();
+        let mut sum = 0;
+        while let Some(value) = stack.try_pop() {
+            sum = sum * 10 + value;
+        }
+        assert_eq!(sum, 321);
+        assert_eq!(stack.try_pop(), None);
+    }
+
+    #[test]
+    fn pop_if_predicate() {
+        let mut stack = ArrayStack::<i32, 4>::from_slice(&[1, 2], 0).unwrap();
+        assert_eq!(stack.pop_if(|&v| v > 5), None);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.pop_if(|&v| v == 2), Some(2));
+        assert_eq!(stack.len(), 1);
+
+        let mut stack = AdvancedArrayStack::<i32, 4>::try_from_iter([1, 2]).unwrap();
+        assert_eq!(stack.pop_if(|&v| v % 2 == 1), None);
+        assert_eq!(stack.peek(), Ok(&2));
+        assert_eq!(stack.pop_if(|&v| v % 2 == 0), Some(2));
+        assert_eq!(stack.peek(), Ok(&1));
+
+        stack.clear();
+        assert_eq!(stack.pop_if(|_| true), None);
+    }
+
+    #[test]
+    fn bottom_stays_fixed() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::new(0);
+        let mut advanced: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::new();
+        assert_eq!(stack.bottom(), Err(ArrayError::Empty));
+        assert_eq!(advanced.bottom(), Err(ArrayError::Empty));
+
+        for value in [5, 6, 7] {
+            stack.push(value).unwrap();
+            advanced.push(value).unwrap();
+            assert_eq!(stack.bottom(), Ok(&5));
+            assert_eq!(advanced.bottom(), Ok(&5));
+        }
+
+        stack.pop().unwrap();
+        advanced.pop().unwrap();
+        assert_eq!(stack.bottom(), Ok(&5));
+        assert_eq!(advanced.bottom(), Ok(&5));
+    }
+
+    #[test]
+    fn hash() {
+        // Same live elements, different leftovers in the unused slots
+        let mut a: ArrayStack<i32, 4> = ArrayStack::new(0);
+        let mut b: ArrayStack<i32, 4> = ArrayStack::new(0);
+        a.push_slice(&[1, 2, 9]).unwrap();
+        a.pop().unwrap();
+        b.push_slice(&[1, 2]).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        b.push(3).unwrap();
+        assert_ne!(hash_of(&a), hash_of(&b));
+
+        let x: AdvancedArrayStack<i32, 4> = AdvancedArrayStack::try_from_iter([5, 6]).unwrap();
+        let mut y: AdvancedArrayStack<i32, 3> = AdvancedArrayStack::new();
+        y.push(5).unwrap();
+        y.push(6).unwrap();
+        assert_eq!(hash_of(&x), hash_of(&y));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut stack: ArrayStack<i32, 4> = ArrayStack::from_slice(&[1, 2, 3], 0).unwrap();
+        if let Some(v) = stack.get_mut(0) {
+            *v = 10;
+        }
+        assert_eq!(stack.get(0), Some(&10));
+        assert_eq!(stack.get(3), None); // Unused slot
+        assert_eq!(stack.get_mut(3), None);
+        assert_eq!(stack.get(usize::MAX), None);
+
+        let mut advanced: AdvancedArrayStack<i32, 4> =
+            AdvancedArrayStack::try_from_iter([1, 2]).unwrap();
+        if let Some(v) = advanced.get_mut(1) {
+            *v = 20;
+        }
+        assert_eq!(advanced.get(1), Some(&20));
+        assert_eq!(advanced.get(2), None);
+        assert_eq!(advanced.get_mut(2), None);
+    }
+
+    #[test]
+    fn iter_double_ended() {
+        let stack: ArrayStack<i32, 4> = ArrayStack::from_slice(&[1, 2, 3], 0).unwrap();
+        assert!(stack.iter().rev().eq(&[3, 2, 1]));
+        assert_eq!(stack.iter().last(), Some(&3));
+
+        let advanced: AdvancedArrayStack<i32, 4> =
+            AdvancedArrayStack::try_from_iter([1, 2, 3]).unwrap();
+        let mut iter = advanced.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.len(), 1);
     }
 }
@@ -0,0 +1,203 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use crate::error::ArrayError;
+use core::fmt;
+use core::ops::Deref;
+
+/// Fixed-capacity UTF-8 string backed by `[u8; N]`
+///
+/// Appends that do not fit are rejected as a whole, so the contents are
+/// always valid UTF-8 and never end in a partial multi-byte character.
+#[derive(Clone, Copy)]
+pub struct SmallString<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> SmallString<N> {
+    /// Create a new empty string
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this string type in bytes
+    ///
+    /// Roughly `N` bytes of storage plus one `usize` for the length.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Append a string slice
+    ///
+    /// # Errors
+    /// Returns `ArrayError::Full` and leaves the contents unchanged if `s`
+    /// does not fit completely.
+    pub fn push_str(&mut self, s: &str) -> Result<(), ArrayError> {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(ArrayError::Full);
+        }
+
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+
+    /// Append a single character
+    ///
+    /// # Errors
+    /// Returns `ArrayError::Full` if the encoded character does not fit.
+    pub fn push(&mut self, c: char) -> Result<(), ArrayError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// View the contents as a string slice
+    pub fn as_str(&self) -> &str {
+        // Safety: Only complete `&str` encodings are ever copied in
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Return the length in bytes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the capacity in bytes
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all contents
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Deref for SmallString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Allows `write!` into the string; a write that does not fit fails with
+/// `fmt::Error`, keeping everything written before it
+impl<const N: usize> fmt::Write for SmallString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> fmt::Display for SmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for SmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for SmallString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for SmallString<N> {}
+
+impl<const N: usize> FixedCapacity for SmallString<N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<const N: usize> Default for SmallString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_append() {
+        let mut s: SmallString<16> = SmallString::new();
+        assert!(s.is_empty());
+        assert_eq!(s.push_str("temp="), Ok(()));
+        assert_eq!(s.push('2'), Ok(()));
+        write!(s, "{}C", 5).unwrap();
+        assert_eq!(s.as_str(), "temp=25C");
+        assert_eq!(s.len(), 8);
+
+        // str methods through Deref
+        assert!(s.starts_with("temp"));
+        assert_eq!(s.find('='), Some(4));
+
+        s.clear();
+        assert_eq!(&*s, "");
+    }
+
+    #[test]
+    fn test_capacity_rejection() {
+        let mut s: SmallString<5> = SmallString::new();
+        assert_eq!(s.push_str("abc"), Ok(()));
+        assert_eq!(s.push_str("def"), Err(ArrayError::Full));
+        assert_eq!(s.as_str(), "abc"); // Unchanged after rejection
+
+        // A 3-byte character does not fit in the 2 remaining bytes
+        assert_eq!(s.push('€'), Err(ArrayError::Full));
+        assert_eq!(s.push('é'), Ok(()));
+        assert!(s.push('x').is_err());
+        assert_eq!(s.as_str(), "abcé");
+        assert_eq!(s.len(), s.capacity());
+
+        assert!(write!(s, "more").is_err());
+    }
+
+    #[test]
+    fn test_always_valid_utf8() {
+        let mut s: SmallString<7> = SmallString::new();
+        for c in ['ß', '€', '𝄞', 'a'] {
+            let _ = s.push(c);
+            assert!(core::str::from_utf8(&s.buf[..s.len]).is_ok());
+        }
+        // 'ß' (2) + '€' (3) = 5 bytes; the 4-byte clef is rejected
+        assert_eq!(s.as_str(), "ß€a");
+    }
+}
@@ -15,6 +15,9 @@
 // with this program; if not, write to the Free Software Foundation, Inc.,
 // 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
 
+use crate::capacity::FixedCapacity;
+use crate::error::ArrayError;
+
 #[derive(Debug, Clone, Copy)]
 struct TreeNode<T> {
     data: T,
@@ -22,6 +25,9 @@ struct TreeNode<T> {
     right: Option<usize>,
 }
 
+/// Array-based binary tree holding at most `N` nodes
+///
+/// `N` must be greater than zero; `new` fails to compile otherwise.
 pub struct ArrayBinaryTree<T, const N: usize> {
     nodes: [Option<TreeNode<T>>; N],
     root: Option<usize>,
@@ -31,6 +37,7 @@ pub struct ArrayBinaryTree<T, const N: usize> {
 impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
     /// Create a new empty tree
     pub fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
         let nodes = [None; N];
         Self {
             nodes,
@@ -39,22 +46,30 @@ impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
         }
     }
 
+    /// Memory footprint of this tree type in bytes
+    ///
+    /// Each of the `N` nodes stores the data and two `Option<usize>` child
+    /// links, so expect well over `N * (size_of::<T>() + 2 * size_of::<usize>())`.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
     /// Insert root node
-    pub fn insert_root(&mut self, data: T) -> Result<(), &'static str> {
+    pub fn insert_root(&mut self, data: T) -> Result<(), ArrayError> {
         if self.root.is_some() {
-            return Err("Root already exists");
+            return Err(ArrayError::AlreadyExists);
         }
         self.insert_node(data, None, None)
             .map(|idx| self.root = Some(idx))
     }
 
     /// Insert left child node
-    pub fn insert_left(&mut self, parent: usize, data: T) -> Result<usize, &'static str> {
+    pub fn insert_left(&mut self, parent: usize, data: T) -> Result<usize, ArrayError> {
         if self.nodes[parent].is_none() {
-            return Err("Invalid parent index");
+            return Err(ArrayError::InvalidIndex);
         }
         if self.nodes[parent].as_ref().unwrap().left.is_some() {
-            return Err("Left child already exists");
+            return Err(ArrayError::AlreadyExists);
         }
         self.insert_node(data, None, None).inspect(|&idx| {
             self.nodes[parent].as_mut().unwrap().left = Some(idx);
@@ -62,12 +77,12 @@ impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
     }
 
     /// Insert right child node
-    pub fn insert_right(&mut self, parent: usize, data: T) -> Result<usize, &'static str> {
+    pub fn insert_right(&mut self, parent: usize, data: T) -> Result<usize, ArrayError> {
         if self.nodes[parent].is_none() {
-            return Err("Invalid parent index");
+            return Err(ArrayError::InvalidIndex);
         }
         if self.nodes[parent].as_ref().unwrap().right.is_some() {
-            return Err("Right child already exists");
+            return Err(ArrayError::AlreadyExists);
         }
         self.insert_node(data, None, None).inspect(|&idx| {
             self.nodes[parent].as_mut().unwrap().right = Some(idx);
@@ -80,9 +95,9 @@ impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
         data: T,
         left: Option<usize>,
         right: Option<usize>,
-    ) -> Result<usize, &'static str> {
+    ) -> Result<usize, ArrayError> {
         if self.next_index >= N {
-            return Err("Tree is full");
+            return Err(ArrayError::Full);
         }
         let idx = self.next_index;
         self.nodes[idx] = Some(TreeNode { data, left, right });
@@ -180,6 +195,124 @@ impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
         }
     }
 
+    /// Look up a node, treating a dangling link as corrupted data
+    fn node_at(&self, idx: usize) -> Result<&TreeNode<T>, ArrayError> {
+        self.nodes
+            .get(idx)
+            .and_then(Option::as_ref)
+            .ok_or(ArrayError::InvalidData)
+    }
+
+    /// Preorder traversal that reports corrupted node links
+    ///
+    /// Unlike `preorder`, this never silently skips children when the
+    /// traversal stack is full and stops on cycles instead of looping.
+    ///
+    /// # Errors
+    /// Returns `ArrayError::InvalidData` if the stack overflows, a link is
+    /// dangling, or more nodes are reached than the tree holds. Nodes visited
+    /// before the problem was found have already been passed to `visit`.
+    pub fn try_preorder<F: FnMut(&T)>(&self, mut visit: F) -> Result<(), ArrayError> {
+        let mut stack = [0; N];
+        let mut sp = 0;
+        let mut visited = 0;
+
+        if let Some(root_idx) = self.root {
+            stack[sp] = root_idx;
+            sp += 1;
+        }
+
+        while sp > 0 {
+            sp -= 1;
+            let node = self.node_at(stack[sp])?;
+            visited += 1;
+            if visited > self.next_index {
+                return Err(ArrayError::InvalidData);
+            }
+            visit(&node.data);
+
+            for child in [node.right, node.left].into_iter().flatten() {
+                if sp >= N {
+                    return Err(ArrayError::InvalidData);
+                }
+                stack[sp] = child;
+                sp += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Inorder traversal that reports corrupted node links
+    ///
+    /// # Errors
+    /// Same conditions as `try_preorder`.
+    pub fn try_inorder<F: FnMut(&T)>(&self, mut visit: F) -> Result<(), ArrayError> {
+        let mut stack = [0; N];
+        let mut sp = 0;
+        let mut visited = 0;
+        let mut current = self.root;
+
+        while current.is_some() || sp > 0 {
+            while let Some(idx) = current {
+                if sp >= N {
+                    return Err(ArrayError::InvalidData);
+                }
+                stack[sp] = idx;
+                sp += 1;
+                current = self.node_at(idx)?.left;
+            }
+
+            sp -= 1;
+            let node = self.node_at(stack[sp])?;
+            visited += 1;
+            if visited > self.next_index {
+                return Err(ArrayError::InvalidData);
+            }
+            visit(&node.data);
+            current = node.right;
+        }
+        Ok(())
+    }
+
+    /// Postorder traversal that reports corrupted node links
+    ///
+    /// # Errors
+    /// Same conditions as `try_preorder`.
+    pub fn try_postorder<F: FnMut(&T)>(&self, mut visit: F) -> Result<(), ArrayError> {
+        let mut stack = [0; N];
+        let mut sp = 0;
+        let mut visited = 0;
+        let mut last_visited = None;
+        let mut current = self.root;
+
+        while current.is_some() || sp > 0 {
+            while let Some(idx) = current {
+                if sp >= N {
+                    return Err(ArrayError::InvalidData);
+                }
+                stack[sp] = idx;
+                sp += 1;
+                current = self.node_at(idx)?.left;
+            }
+
+            let top_idx = stack[sp - 1];
+            let top_node = self.node_at(top_idx)?;
+
+            if top_node.right.is_some() && top_node.right != last_visited {
+                current = top_node.right;
+            } else {
+                sp -= 1;
+                visited += 1;
+                if visited > self.next_index {
+                    return Err(ArrayError::InvalidData);
+                }
+                visit(&top_node.data);
+                last_visited = Some(top_idx);
+            }
+        }
+        Ok(())
+    }
+
     /// Get the total number of nodes in the tree
     pub fn node_count(&self) -> usize {
         self.next_index
@@ -225,8 +358,8 @@ impl<T: Copy, const N: usize> ArrayBinaryTree<T, N> {
             next_size = 0;
 
             // Process all nodes at the current level
-            for i in 0..current_size {
-                let idx = current_queue[i].unwrap();
+            for slot in current_queue.iter().take(current_size) {
+                let idx = slot.unwrap();
                 let node = self.nodes[idx].as_ref().unwrap();
 
                 // Add child nodes to the next level queue
@@ -271,6 +404,16 @@ impl<T: Copy, const N: usize> Default for ArrayBinaryTree<T, N> {
     }
 }
 
+impl<T, const N: usize> FixedCapacity for ArrayBinaryTree<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.next_index
+    }
+}
+
 // Example tests
 #[cfg(test)]
 mod tests {
@@ -416,8 +559,8 @@ mod tests {
         assert_eq!(tree.depth_iterative(), 2);
 
         // Tree should be full
-        // Try to insert into a used-up space without left child, should return "Tree is full"
-        assert_eq!(tree.insert_left(1, 4), Err("Tree is full"));
+        // Try to insert into a used-up space without left child, should return ArrayError::Full
+        assert_eq!(tree.insert_left(1, 4), Err(ArrayError::Full));
     }
 
     #[test]
@@ -467,4 +610,104 @@ mod tests {
         tree.postorder(|_| visited = true);
         assert!(!visited);
     }
+
+    #[test]
+    fn test_insert_errors() {
+        let mut tree: ArrayBinaryTree<i32, 4> = ArrayBinaryTree::new();
+        assert_eq!(tree.insert_left(0, 1), Err(ArrayError::InvalidIndex));
+
+        tree.insert_root(1).unwrap();
+        assert_eq!(tree.insert_root(2), Err(ArrayError::AlreadyExists));
+
+        let left = tree.insert_left(0, 2).unwrap();
+        assert_eq!(tree.insert_left(0, 3), Err(ArrayError::AlreadyExists));
+        assert_eq!(tree.insert_right(3, 3), Err(ArrayError::InvalidIndex));
+
+        tree.insert_right(0, 3).unwrap();
+        assert_eq!(tree.insert_right(0, 4), Err(ArrayError::AlreadyExists));
+
+        tree.insert_left(left, 4).unwrap();
+        assert_eq!(tree.insert_right(left, 5), Err(ArrayError::Full));
+    }
+
+    #[test]
+    fn test_try_traversals() {
+        let mut tree: ArrayBinaryTree<i32, 4> = ArrayBinaryTree::new();
+        tree.insert_root(1).unwrap();
+        let left = tree.insert_left(0, 2).unwrap();
+        tree.insert_right(0, 3).unwrap();
+        tree.insert_left(left, 4).unwrap();
+
+        // Well-formed trees match the infallible traversals
+        let mut out = [0; 4];
+        let mut n = 0;
+        assert_eq!(
+            tree.try_preorder(|&v| {
+                out[n] = v;
+                n += 1;
+            }),
+            Ok(())
+        );
+        assert_eq!(out, [1, 2, 4, 3]);
+
+        n = 0;
+        assert_eq!(
+            tree.try_inorder(|&v| {
+                out[n] = v;
+                n += 1;
+            }),
+            Ok(())
+        );
+        assert_eq!(out, [4, 2, 1, 3]);
+
+        n = 0;
+        assert_eq!(
+            tree.try_postorder(|&v| {
+                out[n] = v;
+                n += 1;
+            }),
+            Ok(())
+        );
+        assert_eq!(out, [4, 2, 3, 1]);
+
+        let empty: ArrayBinaryTree<i32, 2> = ArrayBinaryTree::new();
+        assert_eq!(empty.try_inorder(|_| {}), Ok(()));
+    }
+
+    #[test]
+    fn test_try_traversals_detect_corruption() {
+        let mut tree: ArrayBinaryTree<i32, 4> = ArrayBinaryTree::new();
+        tree.insert_root(1).unwrap();
+        let leaf = tree.insert_left(0, 2).unwrap();
+
+        // Both links of the leaf point back at itself: every pop pushes two
+        // more entries until the traversal stack overflows
+        if let Some(node) = tree.nodes[leaf].as_mut() {
+            node.left = Some(leaf);
+            node.right = Some(leaf);
+        }
+        assert_eq!(tree.try_preorder(|_| {}), Err(ArrayError::InvalidData));
+        assert_eq!(tree.try_inorder(|_| {}), Err(ArrayError::InvalidData));
+        assert_eq!(tree.try_postorder(|_| {}), Err(ArrayError::InvalidData));
+
+        // A single back link never overflows but is caught by the visit count
+        if let Some(node) = tree.nodes[leaf].as_mut() {
+            node.left = None;
+            node.right = Some(0);
+        }
+        let mut visits = 0;
+        assert_eq!(
+            tree.try_preorder(|_| visits += 1),
+            Err(ArrayError::InvalidData)
+        );
+        assert_eq!(visits, 2);
+        assert_eq!(tree.try_inorder(|_| {}), Err(ArrayError::InvalidData));
+        assert_eq!(tree.try_postorder(|_| {}), Err(ArrayError::InvalidData));
+
+        // Dangling link into an unused slot
+        if let Some(node) = tree.nodes[leaf].as_mut() {
+            node.right = Some(3);
+        }
+        assert_eq!(tree.try_preorder(|_| {}), Err(ArrayError::InvalidData));
+    }
 }
@@ -0,0 +1,271 @@
+// A `no_std` and no `alloc` library for more efficient array processing.
+// Copyright (C) 2025  joker2770
+
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation; either version 2 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License along
+// with this program; if not, write to the Free Software Foundation, Inc.,
+// 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+
+use crate::capacity::FixedCapacity;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::slice;
+
+/// Fixed-capacity vector backed by an inline array
+pub struct ArrayVec<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Create a new empty vector
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "Capacity N must be greater than zero") };
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Memory footprint of this vector type in bytes
+    ///
+    /// Roughly `N * size_of::<T>()` plus one `usize` for the length.
+    pub const fn size_bytes() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Append an element to the end
+    /// Returns Err(element) if the vector is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+
+        self.data[self.len].write(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove and return the last element
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // Safety: The slot at `len` was initialized and is no longer tracked
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    /// Insert an element at `index`, shifting later elements to the right
+    /// Returns Err(element) if the vector is full
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) -> Result<(), T> {
+        assert!(index <= self.len, "Insertion index out of range");
+        if self.is_full() {
+            return Err(item);
+        }
+
+        unsafe {
+            let p = self.data.as_mut_ptr().add(index) as *mut T;
+            ptr::copy(p, p.add(1), self.len - index);
+            ptr::write(p, item);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove and return the element at `index`, shifting later elements to
+    /// the left
+    /// Returns None if `index` is out of range
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let item = unsafe {
+            let p = self.data.as_mut_ptr().add(index) as *mut T;
+            let item = ptr::read(p);
+            ptr::copy(p.add(1), p, self.len - index - 1);
+            item
+        };
+        self.len -= 1;
+        Some(item)
+    }
+
+    /// Check if the vector is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if the vector is full
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Return the current number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of elements the vector can hold
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// View the elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: The first `len` elements are initialized
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    /// View the elements as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: The first `len` elements are initialized
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Remove and drop all elements
+    pub fn clear(&mut self) {
+        let live: *mut [T] = self.as_mut_slice();
+        // Reset first so a panicking destructor cannot cause a double drop
+        self.len = 0;
+        unsafe {
+            ptr::drop_in_place(live);
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drop handler to process initialized elements
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> FixedCapacity for ArrayVec<T, N> {
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::DropCounter;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_push_pop() {
+        let mut vec: ArrayVec<i32, 3> = ArrayVec::new();
+        assert!(vec.is_empty());
+        assert_eq!(vec.pop(), None);
+
+        assert_eq!(vec.push(1), Ok(()));
+        assert_eq!(vec.push(2), Ok(()));
+        assert_eq!(vec.push(3), Ok(()));
+        assert!(vec.is_full());
+        assert_eq!(vec.push(4), Err(4)); // Vector is full
+
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.len(), 2);
+
+        // Slice methods through Deref
+        vec.as_mut_slice()[0] = 10;
+        assert_eq!(vec.iter().sum::<i32>(), 12);
+        assert!(vec.contains(&2));
+
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut vec: ArrayVec<i32, 4> = ArrayVec::new();
+        assert_eq!(vec.insert(0, 2), Ok(()));
+        assert_eq!(vec.insert(0, 1), Ok(()));
+        assert_eq!(vec.insert(2, 4), Ok(()));
+        assert_eq!(vec.insert(2, 3), Ok(()));
+        assert_eq!(&*vec, &[1, 2, 3, 4]);
+        assert_eq!(vec.insert(1, 9), Err(9)); // Vector is full
+
+        assert_eq!(vec.remove(1), Some(2));
+        assert_eq!(vec.remove(2), Some(4));
+        assert_eq!(vec.remove(2), None);
+        assert_eq!(&*vec, &[1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insertion index out of range")]
+    fn test_insert_out_of_range() {
+        let mut vec: ArrayVec<i32, 4> = ArrayVec::new();
+        let _ = vec.insert(1, 1);
+    }
+
+    #[test]
+    fn test_drop_once() {
+        let drops = Cell::new(0);
+        {
+            let mut vec: ArrayVec<DropCounter, 4> = ArrayVec::new();
+            for _ in 0..4 {
+                assert!(vec.push(DropCounter(&drops)).is_ok());
+            }
+            // Rejected element is handed back, not leaked or dropped twice
+            let rejected = vec.push(DropCounter(&drops));
+            assert!(rejected.is_err());
+            drop(rejected);
+            assert_eq!(drops.get(), 1);
+
+            drop(vec.remove(0));
+            assert_eq!(drops.get(), 2);
+            drop(vec.pop());
+            assert_eq!(drops.get(), 3);
+        }
+        // The two remaining elements are dropped with the vector
+        assert_eq!(drops.get(), 5);
+    }
+}